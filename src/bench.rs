@@ -0,0 +1,470 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::hint::black_box;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::solution::{panic_message, DynSolution, Part};
+use crate::table;
+
+/// Median durations from a previous run, keyed by day and stage.
+pub type Baseline = BTreeMap<(u32, Stage), Duration>;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub enum Stage {
+    Read,
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 4] = [Stage::Read, Stage::Parse, Stage::Part1, Stage::Part2];
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Stage::Read => "read",
+            Stage::Parse => "parse",
+            Stage::Part1 => "part_1",
+            Stage::Part2 => "part_2",
+        };
+
+        f.pad(name)
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Stage::ALL
+            .into_iter()
+            .find(|stage| stage.to_string() == input)
+            .ok_or_else(|| format!("'{input}' is not a benchmark stage!"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarizes the samples, or `None` if there aren't any.
+    #[must_use]
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+
+        sorted.sort_unstable();
+
+        let median = match sorted.len() {
+            0 => return None,
+            length if length % 2 == 0 => (sorted[length / 2 - 1] + sorted[length / 2]) / 2,
+            length => sorted[length / 2],
+        };
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Measurement {
+    pub day: u32,
+    pub stage: Stage,
+    pub stats: Result<Stats, String>,
+}
+
+impl Measurement {
+    /// Whether the median is more than `threshold_percent` slower than the baseline's.
+    #[must_use]
+    pub fn is_regression(&self, baseline: &Baseline, threshold_percent: u32) -> bool {
+        let (Ok(stats), Some(baseline_median)) =
+            (&self.stats, baseline.get(&(self.day, self.stage)))
+        else {
+            return false;
+        };
+
+        stats.median.as_nanos() * 100
+            > baseline_median.as_nanos() * (100 + u128::from(threshold_percent))
+    }
+}
+
+/// Samples for each stage that has run so far, or the error it failed with.
+type Samples = BTreeMap<Stage, Result<Vec<Duration>, String>>;
+
+/// Times reading, parsing and each part of `solution` separately over `iterations` runs.
+///
+/// A stage that fails to read the input or panics is reported as failed, and the stages that
+/// depend on it aren't run.
+pub fn bench_day<F>(
+    day: u32,
+    solution: &dyn DynSolution,
+    iterations: usize,
+    read_input: F,
+) -> Vec<Measurement>
+where
+    F: Fn() -> Result<Vec<String>, String>,
+{
+    let mut samples = Samples::new();
+
+    for _ in 0..iterations {
+        let Some(input) = time_stage(&mut samples, Stage::Read, &read_input) else {
+            break;
+        };
+
+        let Some(parsed_input) = time_stage(&mut samples, Stage::Parse, || {
+            Ok(solution.parse_input(black_box(&input)))
+        }) else {
+            break;
+        };
+
+        for (stage, part) in [(Stage::Part1, Part::One), (Stage::Part2, Part::Two)] {
+            time_stage(&mut samples, stage, || {
+                Ok(black_box(solution.solve(parsed_input.as_ref(), part)))
+            });
+        }
+    }
+
+    samples
+        .into_iter()
+        .filter_map(|(stage, times)| {
+            let stats = match times {
+                Ok(times) => Ok(Stats::new(&times)?),
+                Err(error) => Err(error),
+            };
+
+            Some(Measurement { day, stage, stats })
+        })
+        .collect()
+}
+
+/// Runs `stage` and records how long it took, or the error it failed or panicked with.
+///
+/// Returns `None` without running it if the stage has already failed.
+fn time_stage<T, F>(samples: &mut Samples, stage: Stage, run: F) -> Option<T>
+where
+    F: FnOnce() -> Result<T, String>,
+{
+    let stage_samples = samples.entry(stage).or_insert_with(|| Ok(Vec::new()));
+
+    let Ok(times) = stage_samples else {
+        return None;
+    };
+
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(run))
+        .unwrap_or_else(|payload| Err(panic_message(payload.as_ref())));
+    let time = start.elapsed();
+
+    match result {
+        Ok(value) => {
+            times.push(time);
+            Some(value)
+        }
+        Err(error) => {
+            *stage_samples = Err(error);
+            None
+        }
+    }
+}
+
+/// Parses a baseline file where each line is `<day> <stage> <median nanoseconds>`.
+///
+/// # Errors
+///
+/// If a line doesn't have exactly three values, or any of them can't be parsed.
+pub fn parse_baseline(lines: &[String]) -> Result<Baseline, String> {
+    let mut baseline = Baseline::new();
+
+    for (line_index, line) in lines.iter().enumerate() {
+        let values: Vec<&str> = line.split_whitespace().collect();
+
+        let parsed = match values[..] {
+            [] => continue,
+            [day, stage, nanos] => day
+                .parse::<u32>()
+                .map_err(|error| error.to_string())
+                .and_then(|day| stage.parse::<Stage>().map(|stage| (day, stage)))
+                .and_then(|key| {
+                    nanos
+                        .parse::<u64>()
+                        .map(|nanos| (key, Duration::from_nanos(nanos)))
+                        .map_err(|error| error.to_string())
+                }),
+            _ => Err("expected '<day> <stage> <nanoseconds>'".to_string()),
+        };
+
+        let (key, median) = parsed.map_err(|error| format!("Line {}: {error}", line_index + 1))?;
+
+        baseline.insert(key, median);
+    }
+
+    Ok(baseline)
+}
+
+/// Formats the medians so they can be read back by [`parse_baseline`], skipping failed stages.
+#[must_use]
+pub fn to_baseline_lines(measurements: &[Measurement]) -> Vec<String> {
+    measurements
+        .iter()
+        .filter_map(|measurement| {
+            let stats = measurement.stats.as_ref().ok()?;
+
+            Some(format!(
+                "{} {} {}",
+                measurement.day,
+                measurement.stage,
+                stats.median.as_nanos()
+            ))
+        })
+        .collect()
+}
+
+/// Formats the measurements as an aligned table, comparing against `baseline` if given.
+#[must_use]
+pub fn report(
+    measurements: &[Measurement],
+    baseline: Option<&Baseline>,
+    threshold_percent: u32,
+) -> String {
+    let rows: Vec<[String; 7]> = measurements
+        .iter()
+        .map(|measurement| {
+            let baseline_median =
+                baseline.and_then(|baseline| baseline.get(&(measurement.day, measurement.stage)));
+
+            let (min, median, max, change) = match &measurement.stats {
+                Err(error) => (
+                    "-".to_string(),
+                    "-".to_string(),
+                    "-".to_string(),
+                    format!("FAILED: {error}"),
+                ),
+                Ok(stats) => {
+                    let change = match (baseline, baseline_median) {
+                        (Some(baseline), Some(baseline_median)) => {
+                            let flag = if measurement.is_regression(baseline, threshold_percent) {
+                                " REGRESSION"
+                            } else {
+                                ""
+                            };
+
+                            format!("{}{flag}", percent_change(*baseline_median, stats.median))
+                        }
+                        _ => "-".to_string(),
+                    };
+
+                    (
+                        format!("{:.2?}", stats.min),
+                        format!("{:.2?}", stats.median),
+                        format!("{:.2?}", stats.max),
+                        change,
+                    )
+                }
+            };
+
+            [
+                measurement.day.to_string(),
+                measurement.stage.to_string(),
+                min,
+                median,
+                max,
+                baseline_median.map_or("-".to_string(), |median| format!("{median:.2?}")),
+                change,
+            ]
+        })
+        .collect();
+
+    table::format_table(
+        ["Day", "Stage", "Min", "Median", "Max", "Baseline", "Change"],
+        &rows,
+    )
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn percent_change(before: Duration, after: Duration) -> String {
+    if before.is_zero() {
+        return "-".to_string();
+    }
+
+    let change = (after.as_nanos() as f64 / before.as_nanos() as f64 - 1.0) * 100.0;
+
+    format!("{change:+.1}%")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::solution::test_support::{Broken, Summer};
+
+    fn measurement(day: u32, stage: Stage, median_ms: u64) -> Measurement {
+        let median = Duration::from_millis(median_ms);
+
+        Measurement {
+            day,
+            stage,
+            stats: Ok(Stats {
+                min: median,
+                median,
+                max: median,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_stats_new() {
+        let samples = [5, 1, 4, 2].map(Duration::from_millis);
+
+        let expected = Stats {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(3),
+            max: Duration::from_millis(5),
+        };
+
+        let result = Stats::new(&samples).unwrap();
+
+        assert_eq!(result, expected);
+        assert_eq!(
+            Stats::new(&samples[..3]).unwrap().median,
+            Duration::from_millis(4)
+        );
+        assert!(Stats::new(&[]).is_none());
+    }
+
+    #[test]
+    fn test_stage_from_str() {
+        for stage in Stage::ALL {
+            assert_eq!(stage.to_string().parse(), Ok(stage));
+        }
+
+        assert!("part_3".parse::<Stage>().is_err());
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let read_input = || Ok(vec!["1".to_string(), "2".to_string()]);
+
+        let result = bench_day(3, &Summer, 5, read_input);
+
+        let result_keys: Vec<(u32, Stage)> = result
+            .iter()
+            .map(|measurement| (measurement.day, measurement.stage))
+            .collect();
+
+        assert_eq!(
+            result_keys,
+            vec![
+                (3, Stage::Read),
+                (3, Stage::Parse),
+                (3, Stage::Part1),
+                (3, Stage::Part2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bench_day_read_err() {
+        let read_input = || Err("File not found!".to_string());
+
+        let expected = vec![Measurement {
+            day: 3,
+            stage: Stage::Read,
+            stats: Err("File not found!".to_string()),
+        }];
+
+        let result = bench_day(3, &Summer, 5, read_input);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bench_day_panic() {
+        let read_input = || Ok(vec!["1".to_string(), "2".to_string()]);
+
+        let result = bench_day(3, &Broken, 5, read_input);
+
+        let result_failed: Vec<(Stage, bool)> = result
+            .iter()
+            .map(|measurement| (measurement.stage, measurement.stats.is_err()))
+            .collect();
+
+        assert_eq!(
+            result_failed,
+            vec![
+                (Stage::Read, false),
+                (Stage::Parse, false),
+                (Stage::Part1, false),
+                (Stage::Part2, true)
+            ]
+        );
+        assert_eq!(
+            result[3].stats,
+            Err("Panicked: Part 2 isn't done!".to_string())
+        );
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let measurements = [
+            measurement(8, Stage::Parse, 2),
+            measurement(8, Stage::Part2, 30),
+        ];
+
+        let expected = Baseline::from([
+            ((8, Stage::Parse), Duration::from_millis(2)),
+            ((8, Stage::Part2), Duration::from_millis(30)),
+        ]);
+
+        let result = parse_baseline(&to_baseline_lines(&measurements)).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_baseline_err() {
+        assert!(parse_baseline(&["8 parse".to_string()]).is_err());
+        assert!(parse_baseline(&["8 sleep 100".to_string()]).is_err());
+        assert!(parse_baseline(&["8 parse soon".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_measurement_is_regression() {
+        let baseline = Baseline::from([((10, Stage::Parse), Duration::from_millis(100))]);
+
+        assert!(!measurement(10, Stage::Parse, 110).is_regression(&baseline, 10));
+        assert!(measurement(10, Stage::Parse, 111).is_regression(&baseline, 10));
+        assert!(!measurement(10, Stage::Part1, 500).is_regression(&baseline, 10));
+    }
+
+    #[test]
+    fn test_report() {
+        let measurements = [
+            measurement(10, Stage::Parse, 150),
+            Measurement {
+                day: 10,
+                stage: Stage::Part1,
+                stats: Err("Panicked: oops".to_string()),
+            },
+        ];
+        let baseline = Baseline::from([((10, Stage::Parse), Duration::from_millis(100))]);
+
+        let expected = "\
+Day | Stage  | Min      | Median   | Max      | Baseline | Change
+10  | parse  | 150.00ms | 150.00ms | 150.00ms | 100.00ms | +50.0% REGRESSION
+10  | part_1 | -        | -        | -        | -        | FAILED: Panicked: oops";
+
+        let result = report(&measurements, Some(&baseline), 10);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,459 @@
+use std::str::FromStr;
+
+use crate::day_1::Day1Options;
+use crate::solution::Part;
+use crate::util::file_reader::InputSource;
+
+pub const DEFAULT_BENCH_ITERATIONS: usize = 10;
+pub const DEFAULT_BENCH_THRESHOLD_PERCENT: u32 = 10;
+
+pub const USAGE: &str = "\
+Usage:
+    advent_of_code_2023 run <day> [--part 1|2] [--input <path>|- | --inline <text>] [--format text|json]
+    advent_of_code_2023 run 1 [--vocabulary <name>|<path>] [--ignore-case] [--explain] ...
+    advent_of_code_2023 run --all [--part 1|2] [--format text|json]
+    advent_of_code_2023 verify [--answers <path>]
+    advent_of_code_2023 bench [<day>] [--iterations <n>] [--baseline <path>] [--threshold <percent>] [--save <path>]
+    advent_of_code_2023 new-day <day> <StructName>
+    advent_of_code_2023 list
+    advent_of_code_2023 help";
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("'{input}' is not a format, expected text or json!")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Command {
+    Run {
+        day: u32,
+        part: Option<Part>,
+        input: Option<InputSource>,
+        format: OutputFormat,
+        day_1: Day1Options,
+    },
+    RunAll {
+        part: Option<Part>,
+        format: OutputFormat,
+    },
+    Verify {
+        answers_file: Option<String>,
+    },
+    Bench {
+        day: Option<u32>,
+        iterations: usize,
+        baseline_file: Option<String>,
+        threshold_percent: u32,
+        save_file: Option<String>,
+    },
+    NewDay {
+        day: u32,
+        struct_name: String,
+    },
+    List,
+    Help,
+}
+
+/// Parses the command line arguments (without the program name) into a [`Command`].
+///
+/// # Errors
+///
+/// If the command is unknown, a flag is missing its value, or a value is out of range.
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    let Some((command, rest)) = args.split_first() else {
+        return Err("No command given!".to_string());
+    };
+
+    match command.as_str() {
+        "run" => parse_run_args(rest),
+        "verify" => parse_verify_args(rest),
+        "bench" => parse_bench_args(rest),
+        "new-day" => parse_new_day_args(rest),
+        "list" => no_extra_args(rest).map(|()| Command::List),
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        _ => Err(format!("Unknown command '{command}'!")),
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<Command, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut all = false;
+    let mut format = OutputFormat::default();
+    let mut day_1 = Day1Options::default();
+
+    let mut args_iter = args.iter();
+
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--part" => part = Some(flag_value(arg, args_iter.next())?.parse()?),
+            "--input" | "--inline" if input.is_some() => {
+                return Err("Cannot use more than one --input or --inline!".to_string());
+            }
+            "--input" => input = Some(parse_input_path(flag_value(arg, args_iter.next())?)),
+            "--inline" => {
+                input = Some(InputSource::Inline(
+                    flag_value(arg, args_iter.next())?.to_string(),
+                ));
+            }
+            "--format" => format = flag_value(arg, args_iter.next())?.parse()?,
+            "--vocabulary" => {
+                day_1.vocabulary = Some(flag_value(arg, args_iter.next())?.to_string());
+            }
+            "--ignore-case" => day_1.ignore_case = true,
+            "--explain" => day_1.explain = true,
+            _ if arg.starts_with("--") => return Err(format!("Unknown flag '{arg}'!")),
+            _ if day.is_some() => return Err(format!("Unexpected argument '{arg}'!")),
+            _ => day = Some(parse_day(arg)?),
+        }
+    }
+
+    if day != Some(1) && day_1 != Day1Options::default() {
+        return Err("--vocabulary, --ignore-case and --explain only apply to day 1!".to_string());
+    }
+
+    if day_1.explain && format == OutputFormat::Json {
+        return Err("Cannot use --explain with --format json!".to_string());
+    }
+
+    match (day, all) {
+        (Some(_), true) => Err("Cannot use both a day and --all!".to_string()),
+        (None, true) if input.is_some() => {
+            Err("Cannot use --input or --inline with --all!".to_string())
+        }
+        (None, true) => Ok(Command::RunAll { part, format }),
+        (Some(day), false) => Ok(Command::Run {
+            day,
+            part,
+            input,
+            format,
+            day_1,
+        }),
+        (None, false) => Err("No day given to run!".to_string()),
+    }
+}
+
+fn parse_verify_args(args: &[String]) -> Result<Command, String> {
+    let mut answers_file = None;
+
+    let mut args_iter = args.iter();
+
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--answers" => answers_file = Some(flag_value(arg, args_iter.next())?.to_string()),
+            _ => return Err(format!("Unexpected argument '{arg}'!")),
+        }
+    }
+
+    Ok(Command::Verify { answers_file })
+}
+
+fn parse_bench_args(args: &[String]) -> Result<Command, String> {
+    let mut day = None;
+    let mut iterations = DEFAULT_BENCH_ITERATIONS;
+    let mut baseline_file = None;
+    let mut threshold_percent = DEFAULT_BENCH_THRESHOLD_PERCENT;
+    let mut save_file = None;
+
+    let mut args_iter = args.iter();
+
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                iterations = parse_number(flag_value(arg, args_iter.next())?)?;
+
+                if iterations == 0 {
+                    return Err("--iterations must be at least 1!".to_string());
+                }
+            }
+            "--baseline" => baseline_file = Some(flag_value(arg, args_iter.next())?.to_string()),
+            "--threshold" => threshold_percent = parse_number(flag_value(arg, args_iter.next())?)?,
+            "--save" => save_file = Some(flag_value(arg, args_iter.next())?.to_string()),
+            _ if arg.starts_with("--") => return Err(format!("Unknown flag '{arg}'!")),
+            _ if day.is_some() => return Err(format!("Unexpected argument '{arg}'!")),
+            _ => day = Some(parse_day(arg)?),
+        }
+    }
+
+    Ok(Command::Bench {
+        day,
+        iterations,
+        baseline_file,
+        threshold_percent,
+        save_file,
+    })
+}
+
+fn parse_new_day_args(args: &[String]) -> Result<Command, String> {
+    match args {
+        [day, struct_name] => Ok(Command::NewDay {
+            day: parse_day(day)?,
+            struct_name: struct_name.clone(),
+        }),
+        _ => Err("new-day expects a day and a struct name!".to_string()),
+    }
+}
+
+fn flag_value<'a>(flag: &str, value: Option<&'a String>) -> Result<&'a str, String> {
+    value
+        .map(String::as_str)
+        .ok_or_else(|| format!("Missing value for '{flag}'!"))
+}
+
+/// `-` means standard input, anything else is a file path.
+fn parse_input_path(value: &str) -> InputSource {
+    match value {
+        "-" => InputSource::Stdin,
+        path => InputSource::Path(path.to_string()),
+    }
+}
+
+fn parse_day(input: &str) -> Result<u32, String> {
+    match input.parse::<u32>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("'{input}' is not a day between 1 and 25!")),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(input: &str) -> Result<T, String> {
+    input
+        .parse()
+        .map_err(|_| format!("'{input}' is not a valid number!"))
+}
+
+fn no_extra_args(args: &[String]) -> Result<(), String> {
+    match args.first() {
+        Some(arg) => Err(format!("Unexpected argument '{arg}'!")),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(input: &str) -> Vec<String> {
+        input.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_args_run() {
+        let expected = Command::Run {
+            day: 5,
+            part: None,
+            input: None,
+            format: OutputFormat::Text,
+            day_1: Day1Options::default(),
+        };
+
+        let result = parse_args(&to_args("run 5")).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_args_run_with_flags() {
+        let expected = Command::Run {
+            day: 10,
+            part: Some(Part::Two),
+            input: Some(InputSource::Path(
+                "test_inputs/day_10_part_2.txt".to_string(),
+            )),
+            format: OutputFormat::Json,
+            day_1: Day1Options::default(),
+        };
+
+        let result = parse_args(&to_args(
+            "run --part 2 10 --input test_inputs/day_10_part_2.txt --format json",
+        ))
+        .unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_args_run_stdin() {
+        let expected = Command::Run {
+            day: 6,
+            part: None,
+            input: Some(InputSource::Stdin),
+            format: OutputFormat::Text,
+            day_1: Day1Options::default(),
+        };
+
+        let result = parse_args(&to_args("run 6 --input -")).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_args_run_inline() {
+        let args = [
+            "run".to_string(),
+            "6".to_string(),
+            "--inline".to_string(),
+            "Time: 7\nDistance: 9".to_string(),
+        ];
+
+        let expected = Command::Run {
+            day: 6,
+            part: None,
+            input: Some(InputSource::Inline("Time: 7\nDistance: 9".to_string())),
+            format: OutputFormat::Text,
+            day_1: Day1Options::default(),
+        };
+
+        let result = parse_args(&args).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_args_run_day_1_options() {
+        let expected = Command::Run {
+            day: 1,
+            part: Some(Part::Two),
+            input: None,
+            format: OutputFormat::Text,
+            day_1: Day1Options {
+                vocabulary: Some("french".to_string()),
+                ignore_case: true,
+                explain: true,
+            },
+        };
+
+        let result = parse_args(&to_args(
+            "run 1 --part 2 --vocabulary french --ignore-case --explain",
+        ))
+        .unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_args_run_all() {
+        let expected = Command::RunAll {
+            part: Some(Part::One),
+            format: OutputFormat::Text,
+        };
+
+        let result = parse_args(&to_args("run --all --part 1")).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_args_verify() {
+        let expected = Command::Verify {
+            answers_file: Some("old_answers.txt".to_string()),
+        };
+
+        let result = parse_args(&to_args("verify --answers old_answers.txt")).unwrap();
+
+        assert_eq!(result, expected);
+        assert_eq!(
+            parse_args(&to_args("verify")),
+            Ok(Command::Verify { answers_file: None })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_bench() {
+        let expected = Command::Bench {
+            day: Some(8),
+            iterations: 50,
+            baseline_file: Some("bench_baseline.txt".to_string()),
+            threshold_percent: 25,
+            save_file: None,
+        };
+
+        let result = parse_args(&to_args(
+            "bench 8 --iterations 50 --baseline bench_baseline.txt --threshold 25",
+        ))
+        .unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_args_bench_defaults() {
+        let expected = Command::Bench {
+            day: None,
+            iterations: DEFAULT_BENCH_ITERATIONS,
+            baseline_file: None,
+            threshold_percent: DEFAULT_BENCH_THRESHOLD_PERCENT,
+            save_file: Some("bench_baseline.txt".to_string()),
+        };
+
+        let result = parse_args(&to_args("bench --save bench_baseline.txt")).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_args_new_day() {
+        let expected = Command::NewDay {
+            day: 11,
+            struct_name: "CosmicExpansion".to_string(),
+        };
+
+        let result = parse_args(&to_args("new-day 11 CosmicExpansion")).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_args_list_and_help() {
+        assert_eq!(parse_args(&to_args("list")), Ok(Command::List));
+        assert_eq!(parse_args(&to_args("help")), Ok(Command::Help));
+        assert_eq!(parse_args(&to_args("--help")), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_parse_args_err() {
+        assert!(parse_args(&[]).is_err());
+        assert!(parse_args(&to_args("walk 5")).is_err());
+        assert!(parse_args(&to_args("run")).is_err());
+        assert!(parse_args(&to_args("run 0")).is_err());
+        assert!(parse_args(&to_args("run 26")).is_err());
+        assert!(parse_args(&to_args("run five")).is_err());
+        assert!(parse_args(&to_args("run 5 6")).is_err());
+        assert!(parse_args(&to_args("run 5 --part 3")).is_err());
+        assert!(parse_args(&to_args("run 5 --part")).is_err());
+        assert!(parse_args(&to_args("run 5 --all")).is_err());
+        assert!(parse_args(&to_args("run --all --input inputs/day_1.txt")).is_err());
+        assert!(parse_args(&to_args("run --all --inline 1")).is_err());
+        assert!(parse_args(&to_args("run 1 --input - --inline 1")).is_err());
+        assert!(parse_args(&to_args("run 5 --verbose")).is_err());
+        assert!(parse_args(&to_args("run 5 --format yaml")).is_err());
+        assert!(parse_args(&to_args("run 5 --vocabulary roman")).is_err());
+        assert!(parse_args(&to_args("run --all --ignore-case")).is_err());
+        assert!(parse_args(&to_args("run 1 --vocabulary")).is_err());
+        assert!(parse_args(&to_args("run 2 --explain")).is_err());
+        assert!(parse_args(&to_args("run 1 --explain --format json")).is_err());
+        assert!(parse_args(&to_args("verify --answers")).is_err());
+        assert!(parse_args(&to_args("verify 5")).is_err());
+        assert!(parse_args(&to_args("bench --iterations 0")).is_err());
+        assert!(parse_args(&to_args("bench --iterations many")).is_err());
+        assert!(parse_args(&to_args("bench --threshold -5")).is_err());
+        assert!(parse_args(&to_args("new-day 11")).is_err());
+        assert!(parse_args(&to_args("new-day 30 Galaxy")).is_err());
+        assert!(parse_args(&to_args("list 5")).is_err());
+    }
+}
@@ -1,13 +1,74 @@
+use std::fmt;
 use std::iter::Sum;
 use std::ops::Add;
 use std::str::FromStr;
+use std::sync::OnceLock;
+
+use super::digit_scanner::DigitScanner;
+use super::explanation::Explanation;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub struct CalibrationValue(pub u32);
 
+/// Which tokens count as digits when parsing a [`CalibrationValue`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ParseMode {
+    /// Only `0` to `9`.
+    DigitsOnly,
+    /// `0` to `9` and the spelled out `"one"` to `"nine"`.
+    DigitsAndWords,
+}
+
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub struct ParseError;
 
+impl ParseMode {
+    /// The scanner for the mode, which is only built once.
+    pub fn scanner(self) -> &'static DigitScanner {
+        static DIGITS_ONLY: OnceLock<DigitScanner> = OnceLock::new();
+        static DIGITS_AND_WORDS: OnceLock<DigitScanner> = OnceLock::new();
+
+        match self {
+            ParseMode::DigitsOnly => DIGITS_ONLY.get_or_init(DigitScanner::digits),
+            ParseMode::DigitsAndWords => {
+                DIGITS_AND_WORDS.get_or_init(DigitScanner::digits_and_words)
+            }
+        }
+    }
+}
+
+impl CalibrationValue {
+    /// Combines the first and last digits in the input, as the mode reads them.
+    ///
+    /// # Errors
+    ///
+    /// If there aren't any digits in the input.
+    pub fn parse_with(input: &str, mode: ParseMode) -> Result<Self, ParseError> {
+        Self::parse_with_scanner(input, mode.scanner())
+    }
+
+    /// Combines the first and last digits in the input, as the scanner reads them.
+    ///
+    /// # Errors
+    ///
+    /// If there aren't any digits in the input.
+    pub fn parse_with_scanner(input: &str, scanner: &DigitScanner) -> Result<Self, ParseError> {
+        Self::explain(input, scanner).map(|explanation| explanation.value())
+    }
+
+    /// The first and last tokens the scanner reads from the input, with where they were found.
+    ///
+    /// # Errors
+    ///
+    /// If there aren't any digits in the input.
+    pub fn explain(input: &str, scanner: &DigitScanner) -> Result<Explanation, ParseError> {
+        scanner
+            .first_and_last(input)
+            .map(|(first, last)| Explanation { first, last })
+            .ok_or(ParseError)
+    }
+}
+
 impl Add for CalibrationValue {
     type Output = CalibrationValue;
 
@@ -16,6 +77,12 @@ impl Add for CalibrationValue {
     }
 }
 
+impl fmt::Display for CalibrationValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 impl Sum for CalibrationValue {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         iter.fold(CalibrationValue(0), |a, b| a + b)
@@ -25,19 +92,9 @@ impl Sum for CalibrationValue {
 impl FromStr for CalibrationValue {
     type Err = ParseError;
 
+    /// Parses in [`ParseMode::DigitsOnly`] mode.
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut first_digit: Option<u32> = None;
-        let mut last_digit: Option<u32> = None;
-
-        for c_digit in input.chars().map(|c| c.to_digit(10)) {
-            first_digit = first_digit.or(c_digit);
-            last_digit = c_digit.or(last_digit);
-        }
-
-        match (first_digit, last_digit) {
-            (Some(x), Some(y)) => Ok(CalibrationValue(x * 10 + y)),
-            _ => Err(ParseError),
-        }
+        Self::parse_with(input, ParseMode::DigitsOnly)
     }
 }
 
@@ -69,6 +126,48 @@ mod tests {
         assert!(no_numbers.parse::<CalibrationValue>().is_err());
     }
 
+    #[test]
+    fn test_parse_with_words() {
+        let inputs = [
+            "two1nine",
+            "eightwothree",
+            "zoneight234",
+            "7pqrstsixteen",
+            "eighthree",
+        ];
+
+        let expected = vec![
+            CalibrationValue(29),
+            CalibrationValue(83),
+            CalibrationValue(14),
+            CalibrationValue(76),
+            CalibrationValue(83),
+        ];
+
+        let result: Vec<CalibrationValue> = inputs
+            .iter()
+            .map(|input| CalibrationValue::parse_with(input, ParseMode::DigitsAndWords).unwrap())
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_with_modes_differ() {
+        assert_eq!(
+            CalibrationValue::parse_with("one2three", ParseMode::DigitsOnly),
+            Ok(CalibrationValue(22))
+        );
+        assert_eq!(
+            CalibrationValue::parse_with("one2three", ParseMode::DigitsAndWords),
+            Ok(CalibrationValue(13))
+        );
+        assert_eq!(
+            CalibrationValue::parse_with("trebuchet", ParseMode::DigitsAndWords),
+            Err(ParseError)
+        );
+    }
+
     #[test]
     fn test_from_str_sum() {
         let values = vec![
@@ -84,4 +183,11 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_display() {
+        let value = CalibrationValue(142);
+
+        assert_eq!(value.to_string(), "142");
+    }
 }
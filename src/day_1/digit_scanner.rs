@@ -0,0 +1,348 @@
+use std::fmt;
+
+/// The words for `1` to `9`, as in the puzzle.
+pub(super) const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Finds digits, written as numbers or as tokens like `"one"`, anywhere in a line, including
+/// tokens that overlap like the `"one"` and `"eight"` in `"oneight"`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DigitScanner {
+    /// A trie of the tokens, where the root is the first node.
+    nodes: Vec<Node>,
+    ignore_case: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+struct Node {
+    children: Vec<(u8, usize)>,
+    value: Option<u32>,
+}
+
+/// Whether a token was written as a number or as a word.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TokenKind {
+    Digit,
+    Word,
+}
+
+/// A token found in a line.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Match {
+    pub value: u32,
+    /// The byte offset the token starts at.
+    pub start: usize,
+    /// The length of the token in bytes.
+    pub len: usize,
+    pub kind: TokenKind,
+}
+
+impl Match {
+    /// The byte offset just after the token.
+    #[must_use]
+    pub fn end(&self) -> usize {
+        self.start + self.len
+    }
+
+    /// The token as written in the line it was found in.
+    #[must_use]
+    pub fn text<'a>(&self, line: &'a str) -> &'a str {
+        &line[self.start..self.end()]
+    }
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TokenKind::Digit => write!(f, "digit"),
+            TokenKind::Word => write!(f, "word"),
+        }
+    }
+}
+
+impl DigitScanner {
+    /// A scanner without any tokens.
+    #[must_use]
+    pub fn new() -> Self {
+        DigitScanner {
+            nodes: vec![Node::default()],
+            ignore_case: false,
+        }
+    }
+
+    /// A scanner without any tokens, which matches them regardless of case.
+    #[must_use]
+    pub fn case_insensitive() -> Self {
+        DigitScanner {
+            ignore_case: true,
+            ..Self::new()
+        }
+    }
+
+    /// A scanner for `0` to `9`.
+    #[must_use]
+    pub fn digits() -> Self {
+        let mut scanner = Self::new();
+
+        for value in 0..10 {
+            scanner.insert(&value.to_string(), value);
+        }
+
+        scanner
+    }
+
+    /// A scanner for `0` to `9` and `"one"` to `"nine"`.
+    #[must_use]
+    pub fn digits_and_words() -> Self {
+        let mut scanner = Self::digits();
+
+        for (value, word) in (1..).zip(DIGIT_WORDS) {
+            scanner.insert(word, value);
+        }
+
+        scanner
+    }
+
+    /// Adds a token, replacing its value if it's already there.
+    pub fn insert(&mut self, token: &str, value: u32) {
+        let token = if self.ignore_case {
+            token.to_lowercase()
+        } else {
+            token.to_string()
+        };
+
+        let mut current = 0;
+
+        for byte in token.bytes() {
+            current = self
+                .child(current, byte)
+                .unwrap_or_else(|| self.add_child(current, byte));
+        }
+
+        self.nodes[current].value = Some(value);
+    }
+
+    /// Every token in the line, in order of where they start.
+    ///
+    /// If several tokens start at the same place, only the longest is included, and tokens that
+    /// are entirely inside an earlier one, like the `"III"` in `"VIII"`, are skipped.
+    pub fn matches<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match> + 'a {
+        let mut covered_until = 0;
+
+        line.char_indices().filter_map(move |(start, _)| {
+            let found = self.match_at(line, start)?;
+
+            if found.end() <= covered_until {
+                return None;
+            }
+
+            covered_until = found.end();
+
+            Some(found)
+        })
+    }
+
+    /// The first and last tokens in the line, which are the same if there's only one.
+    #[must_use]
+    pub fn first_and_last(&self, line: &str) -> Option<(Match, Match)> {
+        self.matches(line).fold(None, |found, current| match found {
+            None => Some((current, current)),
+            Some((first, _)) => Some((first, current)),
+        })
+    }
+
+    fn match_at(&self, line: &str, start: usize) -> Option<Match> {
+        let mut current = 0;
+        let mut longest = None;
+        let mut buffer = [0; 4];
+
+        for (offset, c) in line[start..].char_indices() {
+            let next = if self.ignore_case {
+                c.to_lowercase().try_fold(current, |node, lower| {
+                    self.walk(node, lower.encode_utf8(&mut buffer))
+                })
+            } else {
+                self.walk(current, c.encode_utf8(&mut buffer))
+            };
+
+            let Some(next) = next else {
+                break;
+            };
+
+            current = next;
+
+            if let Some(value) = self.nodes[current].value {
+                let len = offset + c.len_utf8();
+
+                let kind = if line[start..start + len].bytes().all(|b| b.is_ascii_digit()) {
+                    TokenKind::Digit
+                } else {
+                    TokenKind::Word
+                };
+
+                longest = Some(Match {
+                    value,
+                    start,
+                    len,
+                    kind,
+                });
+            }
+        }
+
+        longest
+    }
+
+    fn walk(&self, node: usize, text: &str) -> Option<usize> {
+        text.bytes()
+            .try_fold(node, |current, byte| self.child(current, byte))
+    }
+
+    fn add_child(&mut self, node: usize, byte: u8) -> usize {
+        let child = self.nodes.len();
+
+        self.nodes.push(Node::default());
+        self.nodes[node].children.push((byte, child));
+
+        child
+    }
+
+    fn child(&self, node: usize, byte: u8) -> Option<usize> {
+        self.nodes[node]
+            .children
+            .iter()
+            .find(|(child_byte, _)| *child_byte == byte)
+            .map(|&(_, child)| child)
+    }
+}
+
+impl Default for DigitScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(scanner: &DigitScanner, line: &str) -> Vec<u32> {
+        scanner.matches(line).map(|found| found.value).collect()
+    }
+
+    #[test]
+    fn test_digits() {
+        let scanner = DigitScanner::digits();
+
+        assert_eq!(values(&scanner, "a1b20c"), vec![1, 2, 0]);
+        assert_eq!(values(&scanner, "one"), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_digits_and_words_overlapping() {
+        let scanner = DigitScanner::digits_and_words();
+
+        assert_eq!(values(&scanner, "oneight"), vec![1, 8]);
+        assert_eq!(values(&scanner, "eighthree"), vec![8, 3]);
+        assert_eq!(values(&scanner, "twoneight"), vec![2, 1, 8]);
+        assert_eq!(values(&scanner, "sevenineightwo"), vec![7, 9, 8, 2]);
+        assert_eq!(values(&scanner, "ninine"), vec![9]);
+    }
+
+    #[test]
+    fn test_matches_offsets() {
+        let scanner = DigitScanner::digits_and_words();
+
+        let expected = vec![
+            Match {
+                value: 7,
+                start: 0,
+                len: 1,
+                kind: TokenKind::Digit,
+            },
+            Match {
+                value: 6,
+                start: 6,
+                len: 3,
+                kind: TokenKind::Word,
+            },
+        ];
+
+        let result: Vec<Match> = scanner.matches("7pqrstsixteen").collect();
+
+        assert_eq!(result, expected);
+        assert_eq!(result[1].text("7pqrstsixteen"), "six");
+    }
+
+    #[test]
+    fn test_first_and_last() {
+        let scanner = DigitScanner::digits_and_words();
+
+        let inputs = [
+            "two1nine",
+            "eightwothree",
+            "abcone2threexyz",
+            "xtwone3four",
+            "4nineeightseven2",
+            "zoneight234",
+            "7pqrstsixteen",
+            "jbnrs5bgmsixeightxzjznzkhpvsix5twoneb",
+        ];
+
+        let expected = vec![
+            Some((2, 9)),
+            Some((8, 3)),
+            Some((1, 3)),
+            Some((2, 4)),
+            Some((4, 2)),
+            Some((1, 4)),
+            Some((7, 6)),
+            Some((5, 1)),
+        ];
+
+        let result: Vec<Option<(u32, u32)>> = inputs
+            .iter()
+            .map(|line| {
+                scanner
+                    .first_and_last(line)
+                    .map(|(first, last)| (first.value, last.value))
+            })
+            .collect();
+
+        assert_eq!(result, expected);
+        assert_eq!(scanner.first_and_last("trebuchet"), None);
+    }
+
+    #[test]
+    fn test_longest_token_wins() {
+        let mut scanner = DigitScanner::new();
+
+        scanner.insert("I", 1);
+        scanner.insert("II", 2);
+        scanner.insert("III", 3);
+        scanner.insert("V", 5);
+        scanner.insert("IV", 4);
+
+        assert_eq!(values(&scanner, "xIIIx"), vec![3]);
+        assert_eq!(values(&scanner, "IIxIV"), vec![2, 4]);
+        assert_eq!(values(&scanner, "IIIV"), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let mut scanner = DigitScanner::case_insensitive();
+
+        scanner.insert("Two", 2);
+        scanner.insert("ΣΙΧ", 6);
+
+        assert_eq!(values(&scanner, "TWOtwoσιχ"), vec![2, 2, 6]);
+        assert_eq!(values(&DigitScanner::digits_and_words(), "TWOtwo"), vec![2]);
+    }
+
+    #[test]
+    fn test_multibyte_lines() {
+        let scanner = DigitScanner::digits_and_words();
+
+        assert_eq!(values(&scanner, "é1→two"), vec![1, 2]);
+    }
+}
@@ -0,0 +1,153 @@
+use super::calibration_value::CalibrationValue;
+use super::digit_scanner::{DigitScanner, Match};
+
+/// The first and last tokens read from a line, which make up its calibration value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Explanation {
+    pub first: Match,
+    pub last: Match,
+}
+
+impl Explanation {
+    #[must_use]
+    pub fn value(&self) -> CalibrationValue {
+        CalibrationValue(self.first.value * 10 + self.last.value)
+    }
+
+    /// The line with the first token wrapped in `[]` and the last one in `{}`.
+    ///
+    /// The brackets nest if both are the same token, and interleave if the tokens overlap, like
+    /// `"[on{e]ight}"`.
+    #[must_use]
+    pub fn highlight(&self, line: &str) -> String {
+        // Closing brackets come before opening ones at the same offset, and `{}` goes inside `[]`.
+        let mut markers = [
+            (self.first.start, 1, '['),
+            (self.last.start, 2, '{'),
+            (self.last.end(), 0, '}'),
+            (self.first.end(), 0, ']'),
+        ];
+
+        markers.sort_by_key(|&(offset, order, _)| (offset, order));
+
+        let mut result = String::with_capacity(line.len() + markers.len());
+        let mut copied = 0;
+
+        for (offset, _, marker) in markers {
+            result.push_str(&line[copied..offset]);
+            result.push(marker);
+            copied = offset;
+        }
+
+        result.push_str(&line[copied..]);
+
+        result
+    }
+
+    /// A short description of both tokens, which for `"two1nine9"` is
+    /// `first word "two" at byte 0, last digit "9" at byte 8`.
+    #[must_use]
+    pub fn describe(&self, line: &str) -> String {
+        let describe_token = |token: &Match| {
+            format!(
+                "{} \"{}\" at byte {}",
+                token.kind,
+                token.text(line),
+                token.start
+            )
+        };
+
+        format!(
+            "first {}, last {}",
+            describe_token(&self.first),
+            describe_token(&self.last)
+        )
+    }
+}
+
+/// Every line, numbered from 1, with its tokens highlighted, its calibration value and what each
+/// token was read as.
+#[must_use]
+pub fn listing(lines: &[String], scanner: &DigitScanner) -> String {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let number = index + 1;
+
+            match CalibrationValue::explain(line, scanner) {
+                Ok(explanation) => format!(
+                    "{number:>4} | {} | {} | {}\n",
+                    explanation.highlight(line),
+                    explanation.value(),
+                    explanation.describe(line)
+                ),
+                Err(_) => format!("{number:>4} | {line} | - | no digits found\n"),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::day_1::digit_scanner::TokenKind;
+
+    fn explain(line: &str) -> Explanation {
+        CalibrationValue::explain(line, &DigitScanner::digits_and_words()).unwrap()
+    }
+
+    #[test]
+    fn test_explain() {
+        let expected = Explanation {
+            first: Match {
+                value: 2,
+                start: 1,
+                len: 3,
+                kind: TokenKind::Word,
+            },
+            last: Match {
+                value: 4,
+                start: 7,
+                len: 4,
+                kind: TokenKind::Word,
+            },
+        };
+
+        let result = explain("xtwone3four");
+
+        assert_eq!(result, expected);
+        assert_eq!(result.value(), CalibrationValue(24));
+    }
+
+    #[test]
+    fn test_highlight() {
+        assert_eq!(explain("two1nine").highlight("two1nine"), "[two]1{nine}");
+        assert_eq!(
+            explain("treb7uchet").highlight("treb7uchet"),
+            "treb[{7}]uchet"
+        );
+        assert_eq!(explain("zoneight").highlight("zoneight"), "z[on{e]ight}");
+        assert_eq!(explain("4é2").highlight("4é2"), "[4]é{2}");
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            explain("two1nine9").describe("two1nine9"),
+            "first word \"two\" at byte 0, last digit \"9\" at byte 8"
+        );
+    }
+
+    #[test]
+    fn test_listing() {
+        let lines = vec!["two1nine".to_string(), "trebuchet".to_string()];
+
+        let expected = "   1 | [two]1{nine} | 29 | first word \"two\" at byte 0, last word \"nine\" at byte 4\n   2 | trebuchet | - | no digits found\n";
+
+        let result = listing(&lines, &DigitScanner::digits_and_words());
+
+        assert_eq!(result, expected);
+    }
+}
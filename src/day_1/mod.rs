@@ -1,14 +1,115 @@
 mod calibration_value;
+mod digit_scanner;
+mod explanation;
+mod vocabulary;
+
+use crate::solution::{Part, Solution};
+
+pub use calibration_value::{CalibrationValue, ParseError, ParseMode};
+pub use digit_scanner::{DigitScanner, Match, TokenKind};
+pub use explanation::{listing, Explanation};
+pub use vocabulary::{Vocabulary, VocabularyError};
+
+/// Part 2 reads the digits with the vocabulary, which is English by default.
+#[derive(Debug)]
+pub struct Day1 {
+    scanner: DigitScanner,
+    explaining: bool,
+}
+
+/// Flags that only apply to day 1.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Day1Options {
+    /// A built in vocabulary's name, or the path to a token map.
+    pub vocabulary: Option<String>,
+    pub ignore_case: bool,
+    /// Whether to list which tokens were read from each line before the answers.
+    pub explain: bool,
+}
+
+impl Day1 {
+    #[must_use]
+    pub fn with_vocabulary(vocabulary: &Vocabulary) -> Self {
+        Day1 {
+            scanner: vocabulary.scanner(),
+            explaining: false,
+        }
+    }
+
+    /// Reads part 2 with the vocabulary `options` asks for, explaining the input if asked to.
+    ///
+    /// # Errors
+    ///
+    /// If the vocabulary isn't built in and couldn't be loaded from a file.
+    pub fn from_options(options: &Day1Options) -> Result<Self, String> {
+        let vocabulary = match &options.vocabulary {
+            Some(name_or_path) => Vocabulary::load(name_or_path)?,
+            None => Vocabulary::default(),
+        };
+
+        Ok(Day1 {
+            explaining: options.explain,
+            ..Day1::with_vocabulary(&vocabulary.ignoring_case(options.ignore_case))
+        })
+    }
+
+    /// The scanner that reads the digits for `part`.
+    #[must_use]
+    pub fn scanner(&self, part: Part) -> &DigitScanner {
+        match part {
+            Part::One => ParseMode::DigitsOnly.scanner(),
+            Part::Two => &self.scanner,
+        }
+    }
+
+    /// The [`listing`] of the input, showing which tokens `part` reads from each line.
+    #[must_use]
+    pub fn explain(&self, input: &[String], part: Part) -> String {
+        listing(input, self.scanner(part))
+    }
+
+    /// A titled [`Day1::explain`] for each of `parts`, or nothing unless it was asked for in the
+    /// options.
+    #[must_use]
+    pub fn explanations(&self, input: &[String], parts: &[Part]) -> Vec<String> {
+        if !self.explaining {
+            return Vec::new();
+        }
+
+        parts
+            .iter()
+            .map(|&part| {
+                format!(
+                    "Day 1 Part {part} tokens ([first] and {{last}}):\n{}",
+                    self.explain(input, part)
+                )
+            })
+            .collect()
+    }
+}
+
+impl Default for Day1 {
+    fn default() -> Self {
+        Day1::with_vocabulary(&Vocabulary::default())
+    }
+}
 
-use crate::util::file_reader::to_string_vector;
+impl Solution for Day1 {
+    type Input = Vec<String>;
+    type Part1Answer = CalibrationValue;
+    type Part2Answer = CalibrationValue;
 
-use calibration_value::CalibrationValue;
+    fn parse(&self, input: &[String]) -> Self::Input {
+        input.to_vec()
+    }
 
-pub fn run() {
-    let input = to_string_vector("inputs/day_1.txt").expect("Something went wrong with Day 1!");
+    fn part_1(&self, input: &Self::Input) -> CalibrationValue {
+        part_1(input)
+    }
 
-    println!("Day 1 Part 1: {:?}", part_1(&input));
-    println!("Day 1 Part 2: {:?}", part_2(&input));
+    fn part_2(&self, input: &Self::Input) -> CalibrationValue {
+        part_2(input, &self.scanner)
+    }
 }
 
 fn part_1(inputs: &[String]) -> CalibrationValue {
@@ -18,43 +119,19 @@ fn part_1(inputs: &[String]) -> CalibrationValue {
         .sum()
 }
 
-fn part_2(input: &[String]) -> CalibrationValue {
-    let curated_input = curate_data(input);
-
-    curated_input
+fn part_2(inputs: &[String], scanner: &DigitScanner) -> CalibrationValue {
+    inputs
         .iter()
-        .map(|input| input.parse::<CalibrationValue>().unwrap())
+        .map(|input| CalibrationValue::parse_with_scanner(input, scanner).unwrap())
         .sum()
 }
 
-fn curate_data(input: &[String]) -> Vec<String> {
-    input.iter().map(|line| curate_line(line)).collect()
-}
-
-fn curate_line(line: &str) -> String {
-    line.replace("oneight", "18")
-        .replace("twone", "21")
-        .replace("threeight", "38")
-        .replace("fiveight", "58")
-        .replace("sevenine", "79")
-        .replace("eightwo", "82")
-        .replace("eighthree", "83")
-        .replace("nineight", "98")
-        .replace("one", "1")
-        .replace("two", "2")
-        .replace("three", "3")
-        .replace("four", "4")
-        .replace("five", "5")
-        .replace("six", "6")
-        .replace("seven", "7")
-        .replace("eight", "8")
-        .replace("nine", "9")
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use crate::util::file_reader::to_string_vector;
+
     #[test]
     fn test_part_1() {
         let inputs = to_string_vector("test_inputs/day_1_part_1.txt")
@@ -74,39 +151,84 @@ mod tests {
 
         let expected = CalibrationValue(281);
 
-        let result = part_2(&inputs);
+        let result = part_2(&inputs, &DigitScanner::digits_and_words());
 
         assert_eq!(result, expected);
     }
 
     #[test]
-    fn test_currate_line() {
-        let input = [
-            "two1nine",
-            "eightwothree",
-            "abcone2threexyz",
-            "xtwone3four",
-            "4nineeightseven2",
-            "zoneight234",
-            "7pqrstsixteen",
-            "jbnrs5bgmsixeightxzjznzkhpvsix5twoneb",
-        ];
+    fn test_explain() {
+        let inputs = vec!["one2three".to_string()];
+
+        let day = Day1::default();
+
+        assert_eq!(
+            day.explain(&inputs, Part::One),
+            "   1 | one[{2}]three | 22 | first digit \"2\" at byte 3, last digit \"2\" at byte 3\n"
+        );
+        assert_eq!(
+            day.explain(&inputs, Part::Two),
+            "   1 | [one]2{three} | 13 | first word \"one\" at byte 0, last word \"three\" at byte 4\n"
+        );
+    }
+
+    #[test]
+    fn test_explanations() {
+        let inputs = vec!["one2".to_string()];
+
+        let options = Day1Options {
+            explain: true,
+            ..Day1Options::default()
+        };
 
         let expected = vec![
-            "219".to_string(),
-            "823".to_string(),
-            "abc123xyz".to_string(),
-            "x2134".to_string(),
-            "49872".to_string(),
-            "z18234".to_string(),
-            "7pqrst6teen".to_string(),
-            "jbnrs5bgm68xzjznzkhpv6521b".to_string(),
+            "Day 1 Part 2 tokens ([first] and {last}):\n   1 | [one]{2} | 12 | first word \"one\" at byte 0, last digit \"2\" at byte 3\n".to_string(),
         ];
 
-        let result: Vec<String> = input.iter().map(|line| curate_line(line)).collect();
+        let result = Day1::from_options(&options)
+            .unwrap()
+            .explanations(&inputs, &[Part::Two]);
 
-        for (result_line, expected_line) in result.iter().zip(expected.iter()) {
-            assert_eq!(result_line, expected_line);
-        }
+        assert_eq!(result, expected);
+        assert!(Day1::default()
+            .explanations(&inputs, &[Part::Two])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_from_options() {
+        let inputs = vec!["UNx7".to_string(), "troisDEUX".to_string()];
+
+        let options = Day1Options {
+            vocabulary: Some("french".to_string()),
+            ignore_case: true,
+            explain: false,
+        };
+
+        let day = Day1::from_options(&options).unwrap();
+
+        let expected = CalibrationValue(17 + 32);
+
+        let result = day.part_2(&day.parse(&inputs));
+
+        assert_eq!(result, expected);
+        assert!(Day1::from_options(&Day1Options {
+            vocabulary: Some("test_inputs/missing_vocabulary.txt".to_string()),
+            ..Day1Options::default()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_part_2_with_vocabulary() {
+        let inputs = vec!["UNx7".to_string(), "troisDEUX".to_string()];
+
+        let day = Day1::with_vocabulary(&Vocabulary::french().ignoring_case(true));
+
+        let expected = CalibrationValue(17 + 32);
+
+        let result = day.part_2(&day.parse(&inputs));
+
+        assert_eq!(result, expected);
     }
 }
@@ -0,0 +1,366 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::digit_scanner::{DigitScanner, DIGIT_WORDS};
+
+use crate::util::file_reader::to_string_vector;
+
+const FRENCH: [&str; 9] = [
+    "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+const GERMAN: [&str; 9] = [
+    "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const SPANISH: [&str; 9] = [
+    "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+const ROMAN: [&str; 9] = ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"];
+
+/// The tokens that count as digits in a calibration line, along with the digit each one means.
+///
+/// The ASCII digits `0` to `9` are always included.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Vocabulary {
+    tokens: Vec<(String, u32)>,
+    ignore_case: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VocabularyError {
+    MissingValue { line: usize },
+    EmptyToken { line: usize },
+    InvalidValue { line: usize, value: String },
+}
+
+impl Vocabulary {
+    /// The names accepted by [`Vocabulary::named`].
+    pub const NAMES: [&'static str; 6] =
+        ["digits", "english", "french", "german", "spanish", "roman"];
+
+    /// Only the ASCII digits.
+    #[must_use]
+    pub fn digits() -> Self {
+        Vocabulary {
+            tokens: (0..10).map(|value| (value.to_string(), value)).collect(),
+            ignore_case: false,
+        }
+    }
+
+    /// `"one"` to `"nine"`, as in the puzzle.
+    #[must_use]
+    pub fn english() -> Self {
+        Self::with_words(&DIGIT_WORDS)
+    }
+
+    #[must_use]
+    pub fn french() -> Self {
+        Self::with_words(&FRENCH)
+    }
+
+    #[must_use]
+    pub fn german() -> Self {
+        Self::with_words(&GERMAN)
+    }
+
+    #[must_use]
+    pub fn spanish() -> Self {
+        Self::with_words(&SPANISH)
+    }
+
+    /// `"I"` to `"IX"`, where a longer numeral is read whole rather than as the ones inside it.
+    #[must_use]
+    pub fn roman() -> Self {
+        Self::with_words(&ROMAN)
+    }
+
+    /// One of the built in vocabularies, by its name in [`Vocabulary::NAMES`].
+    #[must_use]
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "digits" => Some(Self::digits()),
+            "english" => Some(Self::english()),
+            "french" => Some(Self::french()),
+            "german" => Some(Self::german()),
+            "spanish" => Some(Self::spanish()),
+            "roman" => Some(Self::roman()),
+            _ => None,
+        }
+    }
+
+    /// The built in vocabulary with the given name, or else the token map in the file at that path.
+    ///
+    /// # Errors
+    ///
+    /// If there's no such vocabulary and the file couldn't be read or parsed.
+    pub fn load(name_or_path: &str) -> Result<Self, String> {
+        if let Some(vocabulary) = Self::named(name_or_path) {
+            return Ok(vocabulary);
+        }
+
+        to_string_vector(name_or_path)
+            .map_err(|error| {
+                let names = Self::NAMES.join(", ");
+
+                format!("'{name_or_path}' isn't one of {names} or a readable token map: {error}")
+            })?
+            .join("\n")
+            .parse()
+            .map_err(|error| format!("Invalid vocabulary in {name_or_path}: {error}"))
+    }
+
+    /// Whether tokens match regardless of case, which is off by default.
+    #[must_use]
+    pub fn ignoring_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Adds a token, replacing its value if it's already there.
+    ///
+    /// # Panics
+    ///
+    /// If the token is empty or the value isn't a single digit.
+    pub fn insert(&mut self, token: &str, value: u32) {
+        assert!(!token.is_empty(), "Token should not be empty!");
+        assert!(value < 10, "Value should be a single digit!");
+
+        match self
+            .tokens
+            .iter_mut()
+            .find(|(existing, _)| existing == token)
+        {
+            Some((_, existing_value)) => *existing_value = value,
+            None => self.tokens.push((token.to_string(), value)),
+        }
+    }
+
+    /// Every token and its digit, in the order they were first added.
+    #[must_use]
+    pub fn tokens(&self) -> &[(String, u32)] {
+        &self.tokens
+    }
+
+    #[must_use]
+    pub fn scanner(&self) -> DigitScanner {
+        let mut scanner = if self.ignore_case {
+            DigitScanner::case_insensitive()
+        } else {
+            DigitScanner::new()
+        };
+
+        for (token, value) in &self.tokens {
+            scanner.insert(token, *value);
+        }
+
+        scanner
+    }
+
+    fn with_words(words: &[&str; 9]) -> Self {
+        let mut vocabulary = Self::digits();
+
+        for (value, word) in (1..).zip(words) {
+            vocabulary.insert(word, value);
+        }
+
+        vocabulary
+    }
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+/// Reads a token map with a `token = digit` pair on each line, on top of the ASCII digits.
+///
+/// Blank lines and anything after a `#` are ignored, and later tokens replace earlier ones.
+impl FromStr for Vocabulary {
+    type Err = VocabularyError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut vocabulary = Self::digits();
+
+        for (index, text) in input.lines().enumerate() {
+            let line = index + 1;
+            let text = text.split_once('#').map_or(text, |(before, _)| before);
+
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            let (token, value) = text
+                .rsplit_once('=')
+                .ok_or(VocabularyError::MissingValue { line })?;
+
+            let token = token.trim();
+            let value = value.trim();
+
+            if token.is_empty() {
+                return Err(VocabularyError::EmptyToken { line });
+            }
+
+            match value.parse() {
+                Ok(digit) if digit < 10 => vocabulary.insert(token, digit),
+                _ => {
+                    return Err(VocabularyError::InvalidValue {
+                        line,
+                        value: value.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(vocabulary)
+    }
+}
+
+impl fmt::Display for VocabularyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VocabularyError::MissingValue { line } => {
+                write!(f, "Line {line}: expected 'token = digit'")
+            }
+            VocabularyError::EmptyToken { line } => write!(f, "Line {line}: the token is empty"),
+            VocabularyError::InvalidValue { line, value } => {
+                write!(f, "Line {line}: '{value}' is not a digit from 0 to 9")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VocabularyError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_and_last(vocabulary: &Vocabulary, line: &str) -> Option<(u32, u32)> {
+        vocabulary
+            .scanner()
+            .first_and_last(line)
+            .map(|(first, last)| (first.value, last.value))
+    }
+
+    #[test]
+    fn test_languages() {
+        assert_eq!(
+            first_and_last(&Vocabulary::english(), "xtwone3four"),
+            Some((2, 4))
+        );
+        assert_eq!(
+            first_and_last(&Vocabulary::french(), "aquatrebdeuxc"),
+            Some((4, 2))
+        );
+        assert_eq!(
+            first_and_last(&Vocabulary::german(), "fünfxacht9neun"),
+            Some((5, 9))
+        );
+        assert_eq!(
+            first_and_last(&Vocabulary::spanish(), "siete1cincoz"),
+            Some((7, 5))
+        );
+        assert_eq!(
+            first_and_last(&Vocabulary::digits(), "one2three"),
+            Some((2, 2))
+        );
+    }
+
+    #[test]
+    fn test_roman() {
+        let vocabulary = Vocabulary::roman();
+
+        assert_eq!(first_and_last(&vocabulary, "xVIIIyIVz"), Some((8, 4)));
+        assert_eq!(first_and_last(&vocabulary, "aIXb"), Some((9, 9)));
+        assert_eq!(first_and_last(&vocabulary, "III"), Some((3, 3)));
+    }
+
+    #[test]
+    fn test_ignoring_case() {
+        assert_eq!(
+            first_and_last(&Vocabulary::english(), "ONE2Three"),
+            Some((2, 2))
+        );
+        assert_eq!(
+            first_and_last(&Vocabulary::english().ignoring_case(true), "ONE2Three"),
+            Some((1, 3))
+        );
+        assert_eq!(
+            first_and_last(&Vocabulary::german().ignoring_case(true), "FÜNFundZwei"),
+            Some((5, 2))
+        );
+        assert_eq!(
+            first_and_last(&Vocabulary::roman().ignoring_case(true), "vii"),
+            Some((7, 7))
+        );
+    }
+
+    #[test]
+    fn test_named() {
+        for name in Vocabulary::NAMES {
+            assert!(Vocabulary::named(name).is_some(), "{name}");
+        }
+
+        assert_eq!(Vocabulary::named("english"), Some(Vocabulary::english()));
+        assert_eq!(Vocabulary::named("klingon"), None);
+    }
+
+    #[test]
+    fn test_insert_replaces() {
+        let mut vocabulary = Vocabulary::digits();
+
+        vocabulary.insert("one", 1);
+        vocabulary.insert("two", 2);
+        vocabulary.insert("one", 7);
+
+        let expected = [("one".to_string(), 7), ("two".to_string(), 2)];
+
+        let result = &vocabulary.tokens()[10..];
+
+        assert_eq!(result, expected);
+        assert_eq!(first_and_last(&vocabulary, "one"), Some((7, 7)));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let input = "# Binary, for some reason\nzero = 0\n\nun = 1 # French\none=1\n";
+
+        let mut expected = Vocabulary::digits();
+
+        expected.insert("zero", 0);
+        expected.insert("un", 1);
+        expected.insert("one", 1);
+
+        let result: Vocabulary = input.parse().unwrap();
+
+        assert_eq!(result, expected);
+        assert_eq!(first_and_last(&result, "zerone"), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_from_str_err() {
+        assert_eq!(
+            "one = 1\ntwo".parse::<Vocabulary>(),
+            Err(VocabularyError::MissingValue { line: 2 })
+        );
+        assert_eq!(
+            " = 1".parse::<Vocabulary>(),
+            Err(VocabularyError::EmptyToken { line: 1 })
+        );
+        assert_eq!(
+            "ten = 10".parse::<Vocabulary>(),
+            Err(VocabularyError::InvalidValue {
+                line: 1,
+                value: "10".to_string(),
+            })
+        );
+        assert_eq!(
+            VocabularyError::InvalidValue {
+                line: 3,
+                value: "x".to_string(),
+            }
+            .to_string(),
+            "Line 3: 'x' is not a digit from 0 to 9"
+        );
+    }
+}
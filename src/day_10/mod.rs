@@ -1,16 +1,27 @@
 mod pipes;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::Solution;
 
-use pipes::PipeNetwork;
+pub use pipes::PipeNetwork;
 
-pub fn run() {
-    let input = to_string_vector("inputs/day_10.txt").expect("Something went wrong with Day 10!");
+pub struct Day10;
 
-    let network = PipeNetwork::new(&input);
+impl Solution for Day10 {
+    type Input = PipeNetwork;
+    type Part1Answer = usize;
+    type Part2Answer = usize;
 
-    println!("Day 10 Part 1: {}", part_1(&network));
-    println!("Day 10 Part 2: {}", part_2(&network));
+    fn parse(&self, input: &[String]) -> Self::Input {
+        PipeNetwork::new(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input)
+    }
 }
 
 fn part_1(network: &PipeNetwork) -> usize {
@@ -25,6 +36,8 @@ fn part_2(network: &PipeNetwork) -> usize {
 mod tests {
     use super::*;
 
+    use crate::util::file_reader::to_string_vector;
+
     #[test]
     fn test_part_1() {
         let input = to_string_vector("test_inputs/day_10_part_1.txt")
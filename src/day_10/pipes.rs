@@ -1,32 +1,40 @@
 use std::collections::HashSet;
 use std::convert::From;
 
+use crate::util::direction::Direction;
+use crate::util::grid::Grid;
+use crate::util::point_2d::Point2d;
+use crate::util::search;
+
 #[derive(Debug, PartialEq)]
 pub struct PipeNetwork {
-    network: Vec<Vec<Pipe>>,
+    network: Grid<Pipe>,
     network_loop: HashSet<(usize, usize)>,
     start_x_location: usize,
     start_y_location: usize,
 }
 
 impl PipeNetwork {
+    /// Creates a new `PipeNetwork`
+    ///
+    /// # Panics
+    ///
+    /// If the rows of the map aren't all the same length, or there isn't a start.
+    #[must_use]
     pub fn new(map: &[String]) -> Self {
-        let mut network: Vec<Vec<Pipe>> = Vec::new();
-
-        for (row_idx, row) in map.iter().enumerate() {
-            let network_row = row
-                .chars()
-                .enumerate()
-                .map(|(col_idx, value)| Pipe::new(col_idx, row_idx, value))
-                .collect();
-
-            network.push(network_row);
-        }
-
-        let start = *network
+        let network = Grid::from_lines(map)
+            .expect("Map should be rectangular!")
+            .map_with_points(|point, &value| {
+                Pipe::new(
+                    usize::try_from(point.x).unwrap(),
+                    usize::try_from(point.y).unwrap(),
+                    value,
+                )
+            });
+
+        let (_, &start) = network
             .iter()
-            .flatten()
-            .find(|pipe| pipe.pipe_type == PipeType::Start)
+            .find(|(_, pipe)| pipe.pipe_type == PipeType::Start)
             .unwrap();
 
         let mut pipe_network = PipeNetwork {
@@ -45,15 +53,17 @@ impl PipeNetwork {
         pipe_network
     }
 
+    #[must_use]
     pub fn length_of_network_loop(&self) -> usize {
         self.network_loop.len()
     }
 
+    #[must_use]
     pub fn number_of_enclosed_tiles(&self) -> usize {
         let mut result = 0;
         let mut previous_type = PipeType::Ground;
 
-        for row in &self.network {
+        for row in self.network.rows() {
             let mut is_outside = true;
 
             for current_type in row.iter().map(|pipe| pipe.pipe_type) {
@@ -80,13 +90,14 @@ impl PipeNetwork {
     }
 
     fn replace_non_loop_tiles(&mut self) {
-        for (row_idx, row) in self.network.iter_mut().enumerate() {
-            for (col_idx, pipe) in row.iter_mut().enumerate() {
-                if !self.network_loop.contains(&(col_idx, row_idx)) {
-                    pipe.set_type(PipeType::Ground);
-                }
+        self.network.transform(|_, pipe| {
+            if !self
+                .network_loop
+                .contains(&(pipe.x_location, pipe.y_location))
+            {
+                pipe.set_type(PipeType::Ground);
             }
-        }
+        });
     }
 
     fn convert_start_in_place(&mut self) {
@@ -99,30 +110,19 @@ impl PipeNetwork {
 
     fn get_loop_locations(&self) -> HashSet<(usize, usize)> {
         let start_location = (self.start_x_location, self.start_y_location);
-        let start_pipe = self
-            .get_pipe(self.start_x_location, self.start_y_location)
-            .unwrap();
-
-        let mut result = HashSet::from([start_location]);
-        let mut current_direction = start_pipe.can_go().unwrap().0;
-        let mut current_location = start_pipe.next_location_going(current_direction).unwrap();
-        let mut current_pipe = self
-            .get_pipe(current_location.0, current_location.1)
-            .unwrap();
-
-        while current_location != start_location {
-            result.insert(current_location);
-
-            current_direction = current_pipe
-                .next_direction_going(current_direction)
-                .unwrap();
-            current_location = current_pipe.next_location_going(current_direction).unwrap();
-            current_pipe = self
-                .get_pipe(current_location.0, current_location.1)
-                .unwrap();
-        }
 
-        result
+        search::bfs_distances(start_location, |&(x_location, y_location)| {
+            let pipe = self.get_pipe(x_location, y_location).unwrap();
+
+            pipe.can_go()
+                .map(|(first, second)| [first, second])
+                .into_iter()
+                .flatten()
+                .filter_map(|direction| pipe.next_location_going(direction))
+                .collect::<Vec<(usize, usize)>>()
+        })
+        .into_keys()
+        .collect()
     }
 
     fn convert_start(&self) -> PipeType {
@@ -134,32 +134,17 @@ impl PipeNetwork {
             return start_pipe.pipe_type;
         }
 
-        let north_location = start_pipe.next_location_going(Direction::North).unwrap();
-        let east_location = start_pipe.next_location_going(Direction::East).unwrap();
-        let south_location = start_pipe.next_location_going(Direction::South).unwrap();
-        let west_location = start_pipe.next_location_going(Direction::West).unwrap();
-
-        let mut can_go_north = self.start_y_location != 0;
-        let mut can_go_west = self.start_x_location != 0;
-
-        let north_pipe = if can_go_north {
-            self.get_pipe(north_location.0, north_location.1)
-        } else {
-            None
+        let can_go = |direction: Direction| {
+            start_pipe
+                .next_location_going(direction)
+                .and_then(|(x_location, y_location)| self.get_pipe(x_location, y_location))
+                .is_some_and(|pipe| pipe.can_be_entered_from(direction.reverse()))
         };
-        let west_pipe = if can_go_west {
-            self.get_pipe(west_location.0, west_location.1)
-        } else {
-            None
-        };
-        let south_pipe = self.get_pipe(south_location.0, south_location.1);
-        let east_pipe = self.get_pipe(east_location.0, east_location.1);
 
-        can_go_north = north_pipe.map_or(false, |pipe| pipe.can_be_entered_from(Direction::South));
-        can_go_west = west_pipe.map_or(false, |pipe| pipe.can_be_entered_from(Direction::East));
-        let can_go_south =
-            south_pipe.map_or(false, |pipe| pipe.can_be_entered_from(Direction::North));
-        let can_go_east = east_pipe.map_or(false, |pipe| pipe.can_be_entered_from(Direction::West));
+        let can_go_north = can_go(Direction::North);
+        let can_go_east = can_go(Direction::East);
+        let can_go_south = can_go(Direction::South);
+        let can_go_west = can_go(Direction::West);
 
         if can_go_north && can_go_east {
             PipeType::NorthEastBend
@@ -180,36 +165,29 @@ impl PipeNetwork {
 
     fn get_pipe_mut(&mut self, x_location: usize, y_location: usize) -> Option<&mut Pipe> {
         self.network
-            .get_mut(y_location)
-            .and_then(|row| row.get_mut(x_location))
+            .get_mut(Self::to_point(x_location, y_location)?)
     }
 
     fn get_pipe(&self, x_location: usize, y_location: usize) -> Option<&Pipe> {
-        self.network
-            .get(y_location)
-            .and_then(|row| row.get(x_location))
+        self.network.get(Self::to_point(x_location, y_location)?)
+    }
+
+    fn to_point(x_location: usize, y_location: usize) -> Option<Point2d<i32>> {
+        Some(Point2d::new(
+            i32::try_from(x_location).ok()?,
+            i32::try_from(y_location).ok()?,
+        ))
     }
 
     #[allow(dead_code)]
     fn to_map(&self) -> Vec<String> {
         self.network
-            .iter()
-            .map(|row| Self::row_to_map(row))
+            .map(|pipe| char::from(pipe.pipe_type))
+            .to_string()
+            .lines()
+            .map(str::to_string)
             .collect()
     }
-
-    #[allow(dead_code)]
-    fn row_to_map(row: &[Pipe]) -> String {
-        row.iter().map(|pipe| char::from(pipe.pipe_type)).collect()
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -256,6 +234,7 @@ impl From<PipeType> for char {
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[allow(clippy::struct_field_names)]
 struct Pipe {
     pipe_type: PipeType,
     x_location: usize,
@@ -278,60 +257,13 @@ impl Pipe {
     }
 
     fn next_location_going(&self, direction: Direction) -> Option<(usize, usize)> {
-        let y_north = self.y_location.saturating_sub(1);
-        let y_south = self.y_location + 1;
-        let x_east = self.x_location + 1;
-        let x_west = self.x_location.saturating_sub(1);
-
-        match (self.pipe_type, direction) {
-            (
-                PipeType::Vertical
-                | PipeType::NorthEastBend
-                | PipeType::NorthWestBend
-                | PipeType::Start,
-                Direction::North,
-            ) => Some((self.x_location, y_north)),
-            (
-                PipeType::Vertical
-                | PipeType::SouthEastBend
-                | PipeType::SouthWestBend
-                | PipeType::Start,
-                Direction::South,
-            ) => Some((self.x_location, y_south)),
-            (
-                PipeType::Horizontal
-                | PipeType::NorthEastBend
-                | PipeType::SouthEastBend
-                | PipeType::Start,
-                Direction::East,
-            ) => Some((x_east, self.y_location)),
-            (
-                PipeType::Horizontal
-                | PipeType::NorthWestBend
-                | PipeType::SouthWestBend
-                | PipeType::Start,
-                Direction::West,
-            ) => Some((x_west, self.y_location)),
-            _ => None,
+        if !self.can_be_entered_from(direction) {
+            return None;
         }
-    }
 
-    fn next_direction_going(&self, direction: Direction) -> Option<Direction> {
-        match (self.pipe_type, direction) {
-            (PipeType::Vertical, Direction::North)
-            | (PipeType::NorthEastBend, Direction::West)
-            | (PipeType::NorthWestBend, Direction::East) => Some(Direction::North),
-            (PipeType::Vertical, Direction::South)
-            | (PipeType::SouthEastBend, Direction::West)
-            | (PipeType::SouthWestBend, Direction::East) => Some(Direction::South),
-            (PipeType::Horizontal, Direction::East)
-            | (PipeType::NorthEastBend, Direction::South)
-            | (PipeType::SouthEastBend, Direction::North) => Some(Direction::East),
-            (PipeType::Horizontal, Direction::West)
-            | (PipeType::NorthWestBend, Direction::South)
-            | (PipeType::SouthWestBend, Direction::North) => Some(Direction::West),
-            _ => None,
-        }
+        direction
+            .step(Point2d::new(self.x_location, self.y_location))
+            .map(|point| (point.x, point.y))
     }
 
     fn can_go(&self) -> Option<(Direction, Direction)> {
@@ -402,6 +334,10 @@ mod tests {
         assert_eq!(south_west_bend_next, Some((0, 1)));
         assert_eq!(ground_next, None);
         assert_eq!(start_next, Some((1, 0)));
+        assert_eq!(
+            Pipe::new(0, 0, 'S').next_location_going(Direction::West),
+            None
+        );
     }
 
     #[test]
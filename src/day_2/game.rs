@@ -8,6 +8,7 @@ pub struct Game {
 }
 
 impl Game {
+    #[must_use]
     pub fn new(id: u32, revealed_cubes: &[CubeCount]) -> Game {
         Game {
             id,
@@ -16,6 +17,7 @@ impl Game {
         }
     }
 
+    #[must_use]
     pub fn can_contain(&self, total_cubes: CubeCount) -> bool {
         self.minimum_required_cubes.blue_cubes <= total_cubes.blue_cubes
             && self.minimum_required_cubes.green_cubes <= total_cubes.green_cubes
@@ -30,6 +32,7 @@ impl Game {
 }
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[allow(clippy::struct_field_names)]
 pub struct CubeCount {
     pub blue_cubes: u32,
     pub green_cubes: u32,
@@ -37,6 +40,7 @@ pub struct CubeCount {
 }
 
 impl CubeCount {
+    #[must_use]
     pub fn new(blue_cubes: u32, green_cubes: u32, red_cubes: u32) -> Self {
         CubeCount {
             blue_cubes,
@@ -45,6 +49,7 @@ impl CubeCount {
         }
     }
 
+    #[must_use]
     pub fn total_maximum(&self, other: &Self) -> Self {
         let max_blue = self.blue_cubes.max(other.blue_cubes);
         let max_green = self.green_cubes.max(other.green_cubes);
@@ -53,6 +58,7 @@ impl CubeCount {
         CubeCount::new(max_blue, max_green, max_red)
     }
 
+    #[must_use]
     pub fn power(&self) -> u32 {
         self.blue_cubes * self.green_cubes * self.red_cubes
     }
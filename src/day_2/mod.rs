@@ -1,15 +1,26 @@
 mod game;
 
-use crate::util::file_reader::to_string_vector;
-use game::{CubeCount, Game};
+use crate::solution::Solution;
+pub use game::{CubeCount, Game, ParseError};
 
-pub fn run() {
-    let input = to_string_vector("inputs/day_2.txt").expect("Something went wrong with Day 2!");
+pub struct Day2;
 
-    let games: Vec<Game> = input.iter().map(|line| line.parse().unwrap()).collect();
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Part1Answer = u32;
+    type Part2Answer = u32;
 
-    println!("Day 2 Part 1: {:?}", part_1(&games));
-    println!("Day 2 Part 2: {:?}", part_2(&games));
+    fn parse(&self, input: &[String]) -> Self::Input {
+        input.iter().map(|line| line.parse().unwrap()).collect()
+    }
+
+    fn part_1(&self, input: &Self::Input) -> u32 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> u32 {
+        part_2(input)
+    }
 }
 
 fn part_1(games: &[Game]) -> u32 {
@@ -33,6 +44,8 @@ fn part_2(games: &[Game]) -> u32 {
 mod tests {
     use super::*;
 
+    use crate::util::file_reader::to_string_vector;
+
     #[test]
     fn test_part_1() {
         let input = to_string_vector("test_inputs/day_2.txt")
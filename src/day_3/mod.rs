@@ -1,16 +1,27 @@
 mod schematic;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::Solution;
 
-use schematic::Schematic;
+pub use schematic::{Number, Schematic};
 
-pub fn run() {
-    let input = to_string_vector("inputs/day_3.txt").expect("Something went wrong with Day 3!");
+pub struct Day3;
 
-    let schematic = Schematic::new(&input);
+impl Solution for Day3 {
+    type Input = Schematic;
+    type Part1Answer = u32;
+    type Part2Answer = u32;
 
-    println!("Day 3 Part 1: {:?}", part_1(&schematic));
-    println!("Day 3 Part 2: {:?}", part_2(&schematic));
+    fn parse(&self, input: &[String]) -> Self::Input {
+        Schematic::new(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> u32 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> u32 {
+        part_2(input)
+    }
 }
 
 fn part_1(schematic: &Schematic) -> u32 {
@@ -25,6 +36,8 @@ fn part_2(schematic: &Schematic) -> u32 {
 mod tests {
     use super::*;
 
+    use crate::util::file_reader::to_string_vector;
+
     #[test]
     fn test_part_1() {
         let input = to_string_vector("test_inputs/day_3.txt")
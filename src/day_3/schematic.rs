@@ -2,9 +2,12 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use crate::util::direction::Heading;
+use crate::util::grid::Grid;
 use crate::util::point_2d::Point2d;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
+#[allow(clippy::struct_field_names)]
 pub struct Number {
     pub value: u32,
     row: usize,
@@ -15,6 +18,7 @@ pub struct Number {
 }
 
 impl Number {
+    #[must_use]
     pub fn new(value: u32, row: usize, col_start: usize, col_end_exclusive: usize) -> Self {
         Number {
             value,
@@ -26,77 +30,70 @@ impl Number {
         }
     }
 
+    /// The points covered by the number's digits, where `x` is the column and `y` the row.
+    ///
+    /// # Panics
+    ///
+    /// If the row or a column doesn't fit in an `i32`.
+    #[must_use]
     pub fn get_location_points(&self) -> Vec<Point2d<i32>> {
+        let row = i32::try_from(self.row).unwrap();
+
         (self.col_start..self.col_end_exclusive)
-            .map(|col| {
-                Point2d::new(
-                    i32::try_from(self.row).unwrap(),
-                    i32::try_from(col).unwrap(),
-                )
-            })
+            .map(|col| Point2d::new(i32::try_from(col).unwrap(), row))
             .collect()
     }
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Schematic {
-    pub diagram: Vec<Vec<char>>,
+    pub diagram: Grid<char>,
     pub numbers: Vec<Rc<RefCell<Number>>>,
-    pub diagram_locations_to_numbers: HashMap<Point2d<i32>, Rc<RefCell<Number>>>,
-    pub diagram_locations_to_symbols: HashMap<Point2d<i32>, char>,
+    /// The index in `numbers` of the number covering each point, if any.
+    number_indexes: Grid<Option<usize>>,
 }
 
 impl Schematic {
+    /// Creates a new Schematic
+    ///
+    /// # Panics
+    ///
+    /// If the rows of the diagram aren't all the same length.
+    #[must_use]
     pub fn new(input: &[String]) -> Self {
-        let diagram = input.iter().map(|row| row.chars().collect()).collect();
-
-        let diagram_locations_to_symbols = input
-            .iter()
-            .enumerate()
-            .flat_map(|(row_number, row)| Self::get_symbols_from_row(row_number, row))
-            .collect();
+        let diagram = Grid::from_lines(input).expect("Diagram should be rectangular!");
 
-        let numbers: Vec<Rc<RefCell<Number>>> = input
-            .iter()
+        let numbers: Vec<Rc<RefCell<Number>>> = diagram
+            .rows()
             .enumerate()
             .flat_map(|(row_number, row)| Self::get_numbers_from_row(row_number, row))
             .map(|number| Rc::new(RefCell::new(number)))
             .collect();
 
-        let mut diagram_locations_to_numbers = HashMap::new();
+        let mut number_indexes = diagram.map(|_| None);
 
-        for number_ref in &numbers {
+        for (index, number_ref) in numbers.iter().enumerate() {
             for point in number_ref.borrow().get_location_points() {
-                diagram_locations_to_numbers.insert(point, Rc::clone(number_ref));
+                if let Some(cell) = number_indexes.get_mut(point) {
+                    *cell = Some(index);
+                }
             }
         }
 
         Schematic {
             diagram,
             numbers,
-            diagram_locations_to_numbers,
-            diagram_locations_to_symbols,
+            number_indexes,
         }
     }
 
+    #[must_use]
     pub fn set_part_numbers(&self) -> Vec<u32> {
-        let mut number_locations = HashMap::new();
-
-        for number_ref in &self.numbers {
-            for point in number_ref.borrow().get_location_points() {
-                number_locations.insert(point, Rc::clone(number_ref));
-            }
-        }
-
-        let symbol_surrounding_locations: Vec<Point2d<i32>> = self
-            .diagram_locations_to_symbols
-            .keys()
-            .flat_map(|point| Self::get_surrounding_points(*point))
-            .collect();
-
-        for point in symbol_surrounding_locations {
-            if let Some(number_ref) = self.diagram_locations_to_numbers.get(&point) {
-                number_ref.borrow_mut().is_part_number = true;
+        for symbol_point in self.symbol_points() {
+            for point in Self::get_surrounding_points(symbol_point) {
+                if let Some(number_ref) = self.number_at(point) {
+                    number_ref.borrow_mut().is_part_number = true;
+                }
             }
         }
 
@@ -107,17 +104,16 @@ impl Schematic {
             .collect()
     }
 
+    #[must_use]
     pub fn get_gear_ratios(&self) -> Vec<u32> {
-        let gear_surrounding_locations: Vec<(Vec<Point2d<i32>>, Point2d<i32>)> = self
-            .diagram_locations_to_symbols
-            .iter()
-            .filter(|(_, symbol)| **symbol == '*')
-            .map(|(point, _)| (Self::get_surrounding_points(*point), *point))
+        let gear_points: Vec<Point2d<i32>> = self
+            .symbol_points()
+            .filter(|&point| self.diagram.get(point) == Some(&'*'))
             .collect();
 
-        for (surrounding_points, gear_point) in gear_surrounding_locations {
-            for point in surrounding_points {
-                if let Some(number_ref) = self.diagram_locations_to_numbers.get(&point) {
+        for gear_point in gear_points {
+            for point in Self::get_surrounding_points(gear_point) {
+                if let Some(number_ref) = self.number_at(point) {
                     number_ref.borrow_mut().related_gear_location = Some(gear_point);
                 }
             }
@@ -140,12 +136,28 @@ impl Schematic {
             .collect()
     }
 
-    fn get_numbers_from_row(row_number: usize, row: &str) -> Vec<Number> {
+    /// The points of everything in the diagram that isn't a digit or a `.`.
+    fn symbol_points(&self) -> impl Iterator<Item = Point2d<i32>> + '_ {
+        self.diagram
+            .iter()
+            .filter(|(_, c)| (**c != '.') && !c.is_ascii_digit())
+            .map(|(point, _)| point)
+    }
+
+    fn number_at(&self, point: Point2d<i32>) -> Option<&Rc<RefCell<Number>>> {
+        self.number_indexes
+            .get(point)
+            .copied()
+            .flatten()
+            .map(|index| &self.numbers[index])
+    }
+
+    fn get_numbers_from_row(row_number: usize, row: &[char]) -> Vec<Number> {
         let mut result = Vec::new();
         let mut temp_value = 0;
         let mut temp_col_start = None;
 
-        for (col_number, c) in row.chars().enumerate() {
+        for (col_number, &c) in row.iter().enumerate() {
             if c.is_ascii_digit() {
                 temp_col_start = temp_col_start.or(Some(col_number));
 
@@ -170,42 +182,12 @@ impl Schematic {
         result
     }
 
-    fn get_symbols_from_row(row_number: usize, row: &str) -> HashMap<Point2d<i32>, char> {
-        row.chars()
-            .enumerate()
-            .filter(|(_, c)| (*c != '.') && !c.is_ascii_digit())
-            .map(|(col_number, c)| {
-                (
-                    Point2d::new(
-                        i32::try_from(row_number).unwrap(),
-                        i32::try_from(col_number).unwrap(),
-                    ),
-                    c,
-                )
-            })
-            .collect()
-    }
-
+    /// The points around `point`, clockwise from the one above it.
     fn get_surrounding_points(point: Point2d<i32>) -> Vec<Point2d<i32>> {
-        let top_left: (i32, i32) = (-1, -1);
-        let top: (i32, i32) = (-1, 0);
-        let top_right: (i32, i32) = (-1, 1);
-        let left: (i32, i32) = (0, -1);
-        let right: (i32, i32) = (0, 1);
-        let bottom_left: (i32, i32) = (1, -1);
-        let bottom: (i32, i32) = (1, 0);
-        let bottom_right: (i32, i32) = (1, 1);
-
-        vec![
-            point.add_t(top_left),
-            point.add_t(top),
-            point.add_t(top_right),
-            point.add_t(left),
-            point.add_t(right),
-            point.add_t(bottom_left),
-            point.add_t(bottom),
-            point.add_t(bottom_right),
-        ]
+        Heading::ALL
+            .iter()
+            .filter_map(|heading| heading.step(point))
+            .collect()
     }
 }
 
@@ -217,7 +199,7 @@ mod tests {
     fn test_number_get_location_points() {
         let number = Number::new(467, 0, 0, 3);
 
-        let expected = vec![Point2d::new(0, 0), Point2d::new(0, 1), Point2d::new(0, 2)];
+        let expected = vec![Point2d::new(0, 0), Point2d::new(1, 0), Point2d::new(2, 0)];
 
         let result = number.get_location_points();
 
@@ -226,17 +208,17 @@ mod tests {
 
     #[test]
     fn test_schematic_get_surrounding_points() {
-        let point = Point2d::new(0, 1);
+        let point = Point2d::new(1, 0);
 
         let expected = vec![
-            Point2d::new(-1, 0),
-            Point2d::new(-1, 1),
-            Point2d::new(-1, 2),
-            Point2d::new(0, 0),
-            Point2d::new(0, 2),
-            Point2d::new(1, 0),
+            Point2d::new(1, -1),
+            Point2d::new(2, -1),
+            Point2d::new(2, 0),
+            Point2d::new(2, 1),
             Point2d::new(1, 1),
-            Point2d::new(1, 2),
+            Point2d::new(0, 1),
+            Point2d::new(0, 0),
+            Point2d::new(0, -1),
         ];
 
         let result = Schematic::get_surrounding_points(point);
@@ -247,23 +229,11 @@ mod tests {
     #[test]
     fn test_schematic_get_numbers_from_row() {
         let row_number = 2;
-        let row = "..35..633.";
+        let row: Vec<char> = "..35..633.".chars().collect();
 
         let expected = vec![Number::new(35, 2, 2, 4), Number::new(633, 2, 6, 9)];
 
-        let result = Schematic::get_numbers_from_row(row_number, row);
-
-        assert_eq!(result, expected);
-    }
-
-    #[test]
-    fn test_schematic_get_symbols_from_row() {
-        let row_number = 4;
-        let row = "617*.....?";
-
-        let expected = HashMap::from([(Point2d::new(4, 3), '*'), (Point2d::new(4, 9), '?')]);
-
-        let result = Schematic::get_symbols_from_row(row_number, row);
+        let result = Schematic::get_numbers_from_row(row_number, &row);
 
         assert_eq!(result, expected);
     }
@@ -298,54 +268,59 @@ mod tests {
 
         let expected_numbers_map = HashMap::from([
             (Point2d::new(0, 0), 467),
-            (Point2d::new(0, 1), 467),
-            (Point2d::new(0, 2), 467),
-            (Point2d::new(0, 5), 114),
-            (Point2d::new(0, 6), 114),
-            (Point2d::new(0, 7), 114),
+            (Point2d::new(1, 0), 467),
+            (Point2d::new(2, 0), 467),
+            (Point2d::new(5, 0), 114),
+            (Point2d::new(6, 0), 114),
+            (Point2d::new(7, 0), 114),
             (Point2d::new(2, 2), 35),
-            (Point2d::new(2, 3), 35),
-            (Point2d::new(2, 6), 633),
-            (Point2d::new(2, 7), 633),
-            (Point2d::new(2, 8), 633),
-            (Point2d::new(4, 0), 617),
-            (Point2d::new(4, 1), 617),
-            (Point2d::new(4, 2), 617),
-            (Point2d::new(5, 7), 58),
-            (Point2d::new(5, 8), 58),
-            (Point2d::new(6, 2), 592),
-            (Point2d::new(6, 3), 592),
-            (Point2d::new(6, 4), 592),
-            (Point2d::new(7, 6), 755),
+            (Point2d::new(3, 2), 35),
+            (Point2d::new(6, 2), 633),
+            (Point2d::new(7, 2), 633),
+            (Point2d::new(8, 2), 633),
+            (Point2d::new(0, 4), 617),
+            (Point2d::new(1, 4), 617),
+            (Point2d::new(2, 4), 617),
+            (Point2d::new(7, 5), 58),
+            (Point2d::new(8, 5), 58),
+            (Point2d::new(2, 6), 592),
+            (Point2d::new(3, 6), 592),
+            (Point2d::new(4, 6), 592),
+            (Point2d::new(6, 7), 755),
             (Point2d::new(7, 7), 755),
-            (Point2d::new(7, 8), 755),
-            (Point2d::new(9, 1), 664),
-            (Point2d::new(9, 2), 664),
-            (Point2d::new(9, 3), 664),
-            (Point2d::new(9, 5), 598),
-            (Point2d::new(9, 6), 598),
-            (Point2d::new(9, 7), 598),
+            (Point2d::new(8, 7), 755),
+            (Point2d::new(1, 9), 664),
+            (Point2d::new(2, 9), 664),
+            (Point2d::new(3, 9), 664),
+            (Point2d::new(5, 9), 598),
+            (Point2d::new(6, 9), 598),
+            (Point2d::new(7, 9), 598),
         ]);
 
         let expected_symbols = HashMap::from([
-            (Point2d::new(1, 3), '*'),
-            (Point2d::new(3, 6), '#'),
-            (Point2d::new(4, 3), '*'),
+            (Point2d::new(3, 1), '*'),
+            (Point2d::new(6, 3), '#'),
+            (Point2d::new(3, 4), '*'),
             (Point2d::new(5, 5), '+'),
-            (Point2d::new(8, 3), '$'),
-            (Point2d::new(8, 5), '*'),
+            (Point2d::new(3, 8), '$'),
+            (Point2d::new(5, 8), '*'),
         ]);
 
         let result = Schematic::new(&input);
 
         let result_numbers_map: HashMap<Point2d<i32>, u32> = result
-            .diagram_locations_to_numbers
-            .iter()
-            .map(|(point, number_ref)| (*point, number_ref.borrow().value))
+            .diagram
+            .points()
+            .filter_map(|point| Some((point, result.number_at(point)?.borrow().value)))
+            .collect();
+
+        let result_symbols: HashMap<Point2d<i32>, char> = result
+            .symbol_points()
+            .map(|point| (point, *result.diagram.get(point).unwrap()))
             .collect();
 
         assert_eq!(result.numbers, expected_numbers);
-        assert_eq!(result.diagram_locations_to_symbols, expected_symbols);
+        assert_eq!(result_symbols, expected_symbols);
         assert_eq!(result_numbers_map, expected_numbers_map);
     }
 
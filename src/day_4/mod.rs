@@ -2,20 +2,31 @@ mod scratch_card;
 
 use std::collections::HashMap;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::Solution;
 
-use scratch_card::ScratchCard;
+pub use scratch_card::{ParseError, ScratchCard};
 
-pub fn run() {
-    let input = to_string_vector("inputs/day_4.txt").expect("Something went wrong with Day 4!");
+pub struct Day4;
 
-    let cards: Vec<ScratchCard> = input
-        .iter()
-        .map(|row| row.parse::<ScratchCard>().unwrap())
-        .collect();
+impl Solution for Day4 {
+    type Input = Vec<ScratchCard>;
+    type Part1Answer = u32;
+    type Part2Answer = u32;
 
-    println!("Day 4 Part 1: {:?}", part_1(&cards));
-    println!("Day 4 Part 2: {:?}", part_2(&cards));
+    fn parse(&self, input: &[String]) -> Self::Input {
+        input
+            .iter()
+            .map(|row| row.parse::<ScratchCard>().unwrap())
+            .collect()
+    }
+
+    fn part_1(&self, input: &Self::Input) -> u32 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> u32 {
+        part_2(input)
+    }
 }
 
 fn part_1(cards: &[ScratchCard]) -> u32 {
@@ -49,6 +60,8 @@ fn part_2(cards: &[ScratchCard]) -> u32 {
 mod tests {
     use super::*;
 
+    use crate::util::file_reader::to_string_vector;
+
     #[test]
     fn test_part_1() {
         let input = to_string_vector("test_inputs/day_4.txt")
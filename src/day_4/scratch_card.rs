@@ -8,6 +8,12 @@ pub struct ScratchCard {
 }
 
 impl ScratchCard {
+    /// One point for the first winning number, doubled for each one after it.
+    ///
+    /// # Panics
+    ///
+    /// If there are more winning numbers than fit in a `u32`.
+    #[must_use]
     pub fn points(&self) -> u32 {
         let number_of_winning_scratched: u32 = self
             .winning_numbers
@@ -23,6 +29,12 @@ impl ScratchCard {
         }
     }
 
+    /// The ids of the cards copied by winning, one for each winning number after this card.
+    ///
+    /// # Panics
+    ///
+    /// If there are more winning numbers than fit in a `u32`.
+    #[must_use]
     pub fn won_copies(&self) -> Vec<u32> {
         let number_of_winning_scratched: u32 = self
             .winning_numbers
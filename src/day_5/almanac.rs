@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use crate::util::file_reader::to_sections;
+
 #[derive(Debug, PartialEq)]
 pub struct Almanac {
     seed_to_soil: Table,
@@ -25,13 +27,18 @@ struct Map {
 }
 
 impl Almanac {
-    /// Creates a new Almanac
+    /// Creates a new Almanac from the `... map:` sections of the input
     ///
     /// # Panics
     ///
     /// If there isn't 7 tables defined.
+    #[must_use]
     pub fn new(input: &[String]) -> Self {
-        let tables: Vec<Table> = input.split(String::is_empty).map(Table::new).collect();
+        let tables: Vec<Table> = to_sections(input)
+            .iter()
+            .filter(|section| section.lines[0].ends_with(" map:"))
+            .map(|section| Table::new(section.lines))
+            .collect();
 
         assert!(
             tables.len() == 7,
@@ -50,6 +57,7 @@ impl Almanac {
         }
     }
 
+    #[must_use]
     pub fn seed_location(&self, seed_id: u64) -> u64 {
         let soil_id = self.seed_to_soil.value_of(seed_id);
         let fertilizer_id = self.soil_to_fertilizer.value_of(soil_id);
@@ -61,6 +69,12 @@ impl Almanac {
         self.humidity_to_location.value_of(humidity_id)
     }
 
+    /// The lowest location of any seed in the `(start, length)` ranges.
+    ///
+    /// # Panics
+    ///
+    /// If there aren't any seed ranges.
+    #[must_use]
     pub fn lowest_location_from_seed_ranges(&self, seed_ranges: &[(u64, u64)]) -> u64 {
         let soil_id_ranges = self.seed_to_soil.mapped_ranges(seed_ranges);
         let fertilizer_id_ranges = self.soil_to_fertilizer.mapped_ranges(&soil_id_ranges);
@@ -88,7 +102,7 @@ impl Table {
 
         let mut mappings: Vec<Map> = input[1..].iter().map(|row| row.parse().unwrap()).collect();
 
-        mappings.sort_by(|a, b| a.source_start.cmp(&b.source_start));
+        mappings.sort_by_key(|map| map.source_start);
 
         Table { title, mappings }
     }
@@ -106,7 +120,7 @@ impl Table {
             .flat_map(|(start, range)| self.mapped_range(*start, *range))
             .collect();
 
-        result.sort_by(|a, b| a.0.cmp(&b.0));
+        result.sort_by_key(|(start, _)| *start);
 
         result
     }
@@ -157,7 +171,7 @@ impl Table {
             result.push((next_start, next_range));
         }
 
-        result.sort_by(|a, b| a.0.cmp(&b.0));
+        result.sort_by_key(|(start, _)| *start);
 
         result
     }
@@ -1,26 +1,35 @@
 mod almanac;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::Solution;
+use crate::util::file_reader::to_sections;
 
-use almanac::Almanac;
+pub use almanac::Almanac;
 
-pub fn run() {
-    let input = to_string_vector("inputs/day_5.txt").expect("Something went wrong with Day 5!");
+pub struct Day5;
 
-    let seeds = get_seed_ids(&input);
+impl Solution for Day5 {
+    type Input = (Vec<u64>, Almanac);
+    type Part1Answer = u64;
+    type Part2Answer = u64;
 
-    let almanac = Almanac::new(&input[2..]);
+    fn parse(&self, input: &[String]) -> Self::Input {
+        (get_seed_ids(input), Almanac::new(input))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> u64 {
+        part_1(&input.0, &input.1)
+    }
 
-    println!("Day 5 Part 1: {:?}", part_1(&seeds, &almanac));
-    println!("Day 5 Part 2: {:?}", part_2(&seeds, &almanac));
+    fn part_2(&self, input: &Self::Input) -> u64 {
+        part_2(&input.0, &input.1)
+    }
 }
 
 fn get_seed_ids(input: &[String]) -> Vec<u64> {
-    input[0]
-        .split(' ')
-        .skip(1)
-        .map(|value| value.parse().unwrap())
-        .collect()
+    to_sections(input)[0]
+        .number_rows()
+        .expect("Seeds should be numbers!")
+        .concat()
 }
 
 fn part_1(seeds: &[u64], almanac: &Almanac) -> u64 {
@@ -45,6 +54,8 @@ fn part_2(seeds: &[u64], almanac: &Almanac) -> u64 {
 mod tests {
     use super::*;
 
+    use crate::util::file_reader::to_string_vector;
+
     #[test]
     fn test_part_1() {
         let input = to_string_vector("test_inputs/day_5.txt")
@@ -52,7 +63,7 @@ mod tests {
 
         let seeds = get_seed_ids(&input);
 
-        let almanac = Almanac::new(&input[2..]);
+        let almanac = Almanac::new(&input);
 
         let expected = 35;
 
@@ -68,7 +79,7 @@ mod tests {
 
         let seeds = get_seed_ids(&input);
 
-        let almanac = Almanac::new(&input[2..]);
+        let almanac = Almanac::new(&input);
 
         let expected = 46;
 
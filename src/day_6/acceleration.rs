@@ -0,0 +1,376 @@
+use std::ops::RangeInclusive;
+
+/// How far a boat travels depending on how long its button is held.
+///
+/// The default methods assume the distance rises with the hold time up to a peak and then falls,
+/// which is true of every model here.
+///
+/// Distances too large for a `u128` saturate at `u128::MAX`, so they never beat a record of
+/// `u128::MAX`. Every model uses `saturating_mul` for any product that could overflow.
+pub trait AccelerationModel {
+    /// The distance travelled when the button is held for `held_ms` of a `time_limit_ms` race.
+    fn distance_mm(&self, held_ms: u64, time_limit_ms: u64) -> u128;
+
+    /// The hold time that travels furthest, or the earliest one if there's a tie.
+    fn optimal_hold_ms(&self, time_limit_ms: u64) -> u64 {
+        first_failing(0, time_limit_ms, |held| {
+            held < time_limit_ms
+                && self.distance_mm(held, time_limit_ms) < self.distance_mm(held + 1, time_limit_ms)
+        })
+        .unwrap_or(time_limit_ms)
+    }
+
+    /// Every hold time that travels further than `record_mm`, or `None` if none of them do.
+    fn winning_hold_ms(&self, time_limit_ms: u64, record_mm: u128) -> Option<RangeInclusive<u64>> {
+        let beats_record = |held: u64| self.distance_mm(held, time_limit_ms) > record_mm;
+
+        let optimal = self.optimal_hold_ms(time_limit_ms);
+
+        if !beats_record(optimal) {
+            return None;
+        }
+
+        let first_win = first_failing(0, optimal, |held| !beats_record(held))?;
+        let last_win = first_failing(optimal, time_limit_ms, beats_record)
+            .map_or(time_limit_ms, |first_loss| first_loss - 1);
+
+        Some(first_win..=last_win)
+    }
+}
+
+/// The speed goes up by `acceleration` mm/ms for every ms the button is held.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Linear {
+    pub acceleration: u64,
+}
+
+impl Default for Linear {
+    fn default() -> Self {
+        Linear { acceleration: 1 }
+    }
+}
+
+impl AccelerationModel for Linear {
+    fn distance_mm(&self, held_ms: u64, time_limit_ms: u64) -> u128 {
+        u128::from(self.acceleration)
+            .saturating_mul(u128::from(held_ms) * u128::from(time_limit_ms - held_ms))
+    }
+
+    fn optimal_hold_ms(&self, time_limit_ms: u64) -> u64 {
+        if self.acceleration == 0 {
+            0
+        } else {
+            time_limit_ms / 2
+        }
+    }
+
+    fn winning_hold_ms(&self, time_limit_ms: u64, record_mm: u128) -> Option<RangeInclusive<u64>> {
+        // A saturated distance can't go further than `u128::MAX`.
+        if self.acceleration == 0 || record_mm == u128::MAX {
+            return None;
+        }
+
+        let time_limit = u128::from(time_limit_ms);
+
+        // `acceleration * x > record` exactly when `x > record / acceleration`, rounding down.
+        let record = record_mm / u128::from(self.acceleration);
+
+        let beats_record = |pressed: u128| pressed * (time_limit - pressed) > record;
+
+        // The distance peaks at half the time limit, so nothing beats the record if that doesn't.
+        if !beats_record(time_limit / 2) {
+            return None;
+        }
+
+        // The first winning press is near the smaller root of `pressed * (time - pressed) = record`,
+        // so only a step or two of correction is ever needed.
+        let discriminant = time_limit * time_limit - 4 * record;
+        let mut first_win = (time_limit - discriminant.isqrt()) / 2;
+
+        while !beats_record(first_win) {
+            first_win += 1;
+        }
+
+        while first_win > 0 && beats_record(first_win - 1) {
+            first_win -= 1;
+        }
+
+        let first_win = u64::try_from(first_win).expect("Press should fit in u64!");
+
+        // Winning presses are symmetric around half the time limit.
+        Some(first_win..=time_limit_ms - first_win)
+    }
+}
+
+/// The speed goes up with the square of the time held, times `acceleration`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct QuadraticCharge {
+    pub acceleration: u64,
+}
+
+impl AccelerationModel for QuadraticCharge {
+    fn distance_mm(&self, held_ms: u64, time_limit_ms: u64) -> u128 {
+        let held = u128::from(held_ms);
+
+        u128::from(self.acceleration)
+            .saturating_mul(held * held)
+            .saturating_mul(u128::from(time_limit_ms - held_ms))
+    }
+}
+
+/// Like [`Linear`], but the speed can't go above `top_speed` mm/ms.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CappedSpeed {
+    pub acceleration: u64,
+    pub top_speed: u64,
+}
+
+impl AccelerationModel for CappedSpeed {
+    fn distance_mm(&self, held_ms: u64, time_limit_ms: u64) -> u128 {
+        let speed =
+            (u128::from(self.acceleration) * u128::from(held_ms)).min(u128::from(self.top_speed));
+
+        speed.saturating_mul(u128::from(time_limit_ms - held_ms))
+    }
+}
+
+/// Like [`Linear`], but once released the boat slows down by `decay` mm/ms every ms until it
+/// stops.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ChargeDecay {
+    pub acceleration: u64,
+    pub decay: u64,
+}
+
+impl AccelerationModel for ChargeDecay {
+    fn distance_mm(&self, held_ms: u64, time_limit_ms: u64) -> u128 {
+        let speed = u128::from(self.acceleration) * u128::from(held_ms);
+        let remaining = u128::from(time_limit_ms - held_ms);
+        let decay = u128::from(self.decay);
+
+        if decay == 0 {
+            return speed.saturating_mul(remaining);
+        }
+
+        let moving = remaining.min(speed.div_ceil(decay));
+
+        if moving == 0 {
+            return 0;
+        }
+
+        // The speeds while moving are a series from `speed` down by `decay` each ms, so the
+        // distance is `moving` times the average of the first and last of them. One of the two
+        // factors is even, which keeps the halving exact before the product can saturate.
+        let first_plus_last = 2 * speed - decay * (moving - 1);
+
+        if moving % 2 == 0 {
+            (moving / 2).saturating_mul(first_plus_last)
+        } else {
+            moving.saturating_mul(first_plus_last / 2)
+        }
+    }
+}
+
+/// The first value in `low..=high` that `predicate` is false for, where it's true for every value
+/// before that one and false for every value after, or `None` if it's true for all of them.
+fn first_failing<P>(mut low: u64, mut high: u64, predicate: P) -> Option<u64>
+where
+    P: Fn(u64) -> bool,
+{
+    if predicate(high) {
+        return None;
+    }
+
+    while low < high {
+        let middle = low + (high - low) / 2;
+
+        if predicate(middle) {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    Some(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Only provides the distance, so the default methods are used.
+    struct Defaults<M>(M);
+
+    impl<M: AccelerationModel> AccelerationModel for Defaults<M> {
+        fn distance_mm(&self, held_ms: u64, time_limit_ms: u64) -> u128 {
+            self.0.distance_mm(held_ms, time_limit_ms)
+        }
+    }
+
+    fn brute_force<M: AccelerationModel>(
+        model: &M,
+        time_limit_ms: u64,
+        record_mm: u128,
+    ) -> (u64, Option<RangeInclusive<u64>>) {
+        let distances: Vec<u128> = (0..=time_limit_ms)
+            .map(|held| model.distance_mm(held, time_limit_ms))
+            .collect();
+
+        let best = *distances.iter().max().unwrap();
+        let optimal = distances
+            .iter()
+            .position(|&distance| distance == best)
+            .unwrap();
+
+        let wins: Vec<u64> = (0..=time_limit_ms)
+            .filter(|&held| distances[usize::try_from(held).unwrap()] > record_mm)
+            .collect();
+
+        (
+            u64::try_from(optimal).unwrap(),
+            wins.first().map(|&first| first..=*wins.last().unwrap()),
+        )
+    }
+
+    fn assert_matches_brute_force<M: AccelerationModel>(model: &M) {
+        for time_limit_ms in 0..40 {
+            for record_mm in (0..2_000).step_by(7) {
+                let expected = brute_force(model, time_limit_ms, record_mm);
+
+                let result = (
+                    model.optimal_hold_ms(time_limit_ms),
+                    model.winning_hold_ms(time_limit_ms, record_mm),
+                );
+
+                assert_eq!(result, expected, "time {time_limit_ms}, record {record_mm}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_linear() {
+        let model = Linear::default();
+
+        assert_eq!(model.distance_mm(2, 7), 10);
+        assert_eq!(model.optimal_hold_ms(7), 3);
+        assert_eq!(model.winning_hold_ms(7, 9), Some(2..=5));
+        assert_eq!(model.winning_hold_ms(7, 12), None);
+        assert_eq!(Linear { acceleration: 0 }.winning_hold_ms(7, 0), None);
+    }
+
+    #[test]
+    fn test_linear_overflow() {
+        let model = Linear {
+            acceleration: u64::MAX,
+        };
+
+        assert_eq!(model.distance_mm(1 << 40, 1 << 41), u128::MAX);
+        assert_eq!(model.winning_hold_ms(1 << 41, u128::MAX), None);
+        assert_eq!(
+            model.winning_hold_ms(1 << 41, u128::MAX - 1),
+            Defaults(model).winning_hold_ms(1 << 41, u128::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn test_linear_matches_brute_force() {
+        for acceleration in 0..4 {
+            assert_matches_brute_force(&Linear { acceleration });
+            assert_matches_brute_force(&Defaults(Linear { acceleration }));
+        }
+    }
+
+    #[test]
+    fn test_quadratic_charge() {
+        let model = QuadraticCharge { acceleration: 1 };
+
+        assert_eq!(model.distance_mm(4, 6), 32);
+        assert_eq!(model.optimal_hold_ms(6), 4);
+        assert_matches_brute_force(&model);
+    }
+
+    #[test]
+    fn test_quadratic_charge_overflow() {
+        let model = QuadraticCharge {
+            acceleration: u64::MAX,
+        };
+
+        assert_eq!(model.distance_mm(1 << 40, 1 << 41), u128::MAX);
+        assert_eq!(model.winning_hold_ms(1 << 41, u128::MAX), None);
+    }
+
+    #[test]
+    fn test_capped_speed() {
+        let model = CappedSpeed {
+            acceleration: 2,
+            top_speed: 5,
+        };
+
+        assert_eq!(model.distance_mm(2, 10), 32);
+        assert_eq!(model.distance_mm(4, 10), 30);
+        assert_eq!(model.optimal_hold_ms(10), 3);
+        assert_matches_brute_force(&model);
+    }
+
+    #[test]
+    fn test_capped_speed_overflow() {
+        let model = CappedSpeed {
+            acceleration: u64::MAX,
+            top_speed: u64::MAX,
+        };
+
+        assert_eq!(
+            model.distance_mm(1 << 40, 1 << 41),
+            u128::from(u64::MAX) << 40
+        );
+        assert_eq!(
+            model.distance_mm(1, u64::MAX),
+            u128::from(u64::MAX) * u128::from(u64::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn test_charge_decay() {
+        let model = ChargeDecay {
+            acceleration: 3,
+            decay: 2,
+        };
+
+        assert_eq!(model.distance_mm(2, 10), 6 + 4 + 2);
+        assert_eq!(model.distance_mm(8, 10), 24 + 22);
+        assert_matches_brute_force(&model);
+        assert_matches_brute_force(&ChargeDecay {
+            acceleration: 1,
+            decay: 0,
+        });
+    }
+    #[test]
+    fn test_charge_decay_overflow() {
+        let model = ChargeDecay {
+            acceleration: u64::MAX,
+            decay: 1,
+        };
+
+        assert_eq!(model.distance_mm(1 << 40, 1 << 41), u128::MAX);
+        assert_eq!(
+            ChargeDecay {
+                acceleration: u64::MAX,
+                decay: 0
+            }
+            .distance_mm(1 << 40, 1 << 41),
+            u128::MAX
+        );
+
+        // Close to `u128::MAX`, but still exact.
+        let held = 5_u64 << 30;
+        let model = ChargeDecay {
+            acceleration: u64::MAX,
+            decay: u64::MAX,
+        };
+
+        assert_eq!(
+            model.distance_mm(held, 2 * held),
+            u128::from(u64::MAX) * (u128::from(held) * u128::from(held + 1) / 2)
+        );
+    }
+}
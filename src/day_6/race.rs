@@ -1,31 +1,55 @@
 use std::convert::From;
+use std::ops::RangeInclusive;
+
+use super::acceleration::{AccelerationModel, Linear};
 
 #[derive(Debug, PartialEq)]
 pub struct Race {
-    time_limit_ms: f64,
-    distance_record_mm: f64,
+    time_limit_ms: u64,
+    distance_record_mm: u64,
+}
+
+/// How a race can be won with a particular [`AccelerationModel`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RaceOutcome {
+    pub winning_hold_ms: RangeInclusive<u64>,
+    pub optimal_hold_ms: u64,
+    /// How much further than the record the optimal hold time goes.
+    pub margin_mm: u128,
 }
 
 impl Race {
-    pub fn number_of_pressing_milliseconds_to_beat_record(&self) -> f64 {
-        let sqrt_part = self.quadratic_sqrt_part();
+    /// The number of whole milliseconds the button can be held for to go further than the record.
+    #[must_use]
+    pub fn number_of_pressing_milliseconds_to_beat_record(&self) -> u64 {
+        self.outcome(&Linear::default())
+            .map_or(0, |outcome| outcome.number_of_ways_to_win())
+    }
 
-        let zero_1 = (self.time_limit_ms - sqrt_part) / 2.0;
-        let zero_2 = (self.time_limit_ms + sqrt_part) / 2.0;
+    /// How the record can be beaten with `model`, or `None` if it can't.
+    pub fn outcome<M: AccelerationModel>(&self, model: &M) -> Option<RaceOutcome> {
+        let record = u128::from(self.distance_record_mm);
 
-        let zero_1_fixed = (zero_1 + 1.0).floor();
-        let zero_2_fixed = (zero_2 - 1.0).ceil();
+        let winning_hold_ms = model.winning_hold_ms(self.time_limit_ms, record)?;
+        let optimal_hold_ms = model.optimal_hold_ms(self.time_limit_ms);
 
-        zero_2_fixed - zero_1_fixed + 1.0
+        Some(RaceOutcome {
+            winning_hold_ms,
+            optimal_hold_ms,
+            margin_mm: model.distance_mm(optimal_hold_ms, self.time_limit_ms) - record,
+        })
     }
+}
 
-    fn quadratic_sqrt_part(&self) -> f64 {
-        (self.time_limit_ms * self.time_limit_ms - 4.0 * self.distance_record_mm).sqrt()
+impl RaceOutcome {
+    #[must_use]
+    pub fn number_of_ways_to_win(&self) -> u64 {
+        self.winning_hold_ms.end() - self.winning_hold_ms.start() + 1
     }
 }
 
-impl From<(f64, f64)> for Race {
-    fn from(input: (f64, f64)) -> Self {
+impl From<(u64, u64)> for Race {
+    fn from(input: (u64, u64)) -> Self {
         Race {
             time_limit_ms: input.0,
             distance_record_mm: input.1,
@@ -39,11 +63,11 @@ mod tests {
 
     #[test]
     fn test_from_tuple() {
-        let input = (30.0, 200.0);
+        let input = (30, 200);
 
         let expected = Race {
-            time_limit_ms: 30.0,
-            distance_record_mm: 200.0,
+            time_limit_ms: 30,
+            distance_record_mm: 200,
         };
 
         let result = Race::from(input);
@@ -53,12 +77,59 @@ mod tests {
 
     #[test]
     fn test_number_of_pressing_milliseconds_to_beat_record() {
-        let race = Race::from((30.0, 200.0));
+        let race = Race::from((30, 200));
 
-        let expected = 9.0;
+        let expected = 9;
 
         let result = race.number_of_pressing_milliseconds_to_beat_record();
 
-        assert!((result - expected).abs() < f64::EPSILON);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_number_of_pressing_milliseconds_to_beat_record_brute_force() {
+        for time_limit in 0..60 {
+            for record in 0..1_000 {
+                let expected = (0..=time_limit)
+                    .filter(|pressed| pressed * (time_limit - pressed) > record)
+                    .count();
+
+                let result = Race::from((time_limit, record))
+                    .number_of_pressing_milliseconds_to_beat_record();
+
+                assert_eq!(
+                    result,
+                    u64::try_from(expected).unwrap(),
+                    "time {time_limit}, record {record}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_number_of_pressing_milliseconds_to_beat_record_large() {
+        let race = Race::from((u64::MAX, u64::MAX));
+
+        let expected = u64::MAX - 3;
+
+        let result = race.number_of_pressing_milliseconds_to_beat_record();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_outcome() {
+        let race = Race::from((7, 9));
+
+        let expected = Some(RaceOutcome {
+            winning_hold_ms: 2..=5,
+            optimal_hold_ms: 3,
+            margin_mm: 3,
+        });
+
+        let result = race.outcome(&Linear::default());
+
+        assert_eq!(result, expected);
+        assert_eq!(Race::from((7, 12)).outcome(&Linear::default()), None);
     }
 }
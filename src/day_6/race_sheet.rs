@@ -0,0 +1,319 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::race::Race;
+
+const TIME_HEADER: &str = "Time";
+const DISTANCE_HEADER: &str = "Distance";
+
+/// The times and distance records of the races, read both as separate races and as one race with
+/// bad kerning.
+#[derive(Debug, PartialEq)]
+pub struct RaceSheet {
+    races: Vec<Race>,
+    /// Kept as an error rather than failing the whole sheet, since the separate races may still fit.
+    kerned_race: Result<Race, RaceSheetError>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RaceSheetError {
+    MissingLine {
+        header: &'static str,
+    },
+    UnexpectedHeader {
+        line: usize,
+        expected: &'static str,
+        found: String,
+    },
+    InvalidNumber {
+        line: usize,
+        value: String,
+    },
+    TooLarge {
+        line: usize,
+        value: String,
+    },
+    ColumnCountMismatch {
+        times: usize,
+        distances: usize,
+    },
+    NoRaces,
+    UnexpectedLine {
+        line: usize,
+    },
+}
+
+impl RaceSheet {
+    #[must_use]
+    pub fn races(&self) -> &[Race] {
+        &self.races
+    }
+
+    /// The single race found by ignoring the spaces between the numbers.
+    ///
+    /// # Errors
+    ///
+    /// If the time or distance is too large once the spaces are ignored.
+    pub fn kerned_race(&self) -> Result<&Race, &RaceSheetError> {
+        self.kerned_race.as_ref()
+    }
+}
+
+impl FromStr for RaceSheet {
+    type Err = RaceSheetError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line))
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let times = parse_row(lines.next(), TIME_HEADER)?;
+        let distances = parse_row(lines.next(), DISTANCE_HEADER)?;
+
+        if let Some((line, _)) = lines.next() {
+            return Err(RaceSheetError::UnexpectedLine { line });
+        }
+
+        if times.values.len() != distances.values.len() {
+            return Err(RaceSheetError::ColumnCountMismatch {
+                times: times.values.len(),
+                distances: distances.values.len(),
+            });
+        }
+
+        if times.values.is_empty() {
+            return Err(RaceSheetError::NoRaces);
+        }
+
+        let races = times
+            .values
+            .iter()
+            .zip(&distances.values)
+            .map(|(&time, &distance)| Race::from((time, distance)))
+            .collect();
+
+        let kerned_race = times
+            .kerned()
+            .and_then(|time| Ok(Race::from((time, distances.kerned()?))));
+
+        Ok(RaceSheet { races, kerned_race })
+    }
+}
+
+impl fmt::Display for RaceSheetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RaceSheetError::MissingLine { header } => write!(f, "Missing the {header} line"),
+            RaceSheetError::UnexpectedHeader {
+                line,
+                expected,
+                found,
+            } => write!(f, "Line {line}: expected '{expected}:', found '{found}'"),
+            RaceSheetError::InvalidNumber { line, value } => {
+                write!(f, "Line {line}: '{value}' is not a number")
+            }
+            RaceSheetError::TooLarge { line, value } => {
+                write!(f, "Line {line}: {value} is too large")
+            }
+            RaceSheetError::ColumnCountMismatch { times, distances } => {
+                write!(f, "Found {times} time(s) but {distances} distance(s)")
+            }
+            RaceSheetError::NoRaces => write!(f, "There aren't any races"),
+            RaceSheetError::UnexpectedLine { line } => {
+                write!(f, "Line {line}: expected nothing after the distances")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RaceSheetError {}
+
+/// The numbers after a header, along with the text they came from for kerning.
+struct Row<'a> {
+    line: usize,
+    columns: Vec<&'a str>,
+    values: Vec<u64>,
+}
+
+impl Row<'_> {
+    fn kerned(&self) -> Result<u64, RaceSheetError> {
+        let value = self.columns.concat();
+
+        value.parse().map_err(|_| RaceSheetError::TooLarge {
+            line: self.line,
+            value,
+        })
+    }
+}
+
+fn parse_row<'a>(
+    numbered_line: Option<(usize, &'a str)>,
+    header: &'static str,
+) -> Result<Row<'a>, RaceSheetError> {
+    let (line, text) = numbered_line.ok_or(RaceSheetError::MissingLine { header })?;
+
+    let columns_text = match text.split_once(':') {
+        Some((found, columns_text)) if found.trim() == header => columns_text,
+        Some((found, _)) => {
+            return Err(RaceSheetError::UnexpectedHeader {
+                line,
+                expected: header,
+                found: found.trim().to_string(),
+            })
+        }
+        None => {
+            return Err(RaceSheetError::UnexpectedHeader {
+                line,
+                expected: header,
+                found: text.trim().to_string(),
+            })
+        }
+    };
+
+    let columns: Vec<&str> = columns_text.split_whitespace().collect();
+
+    let values = columns
+        .iter()
+        .map(|&column| {
+            if !column.chars().all(|c| c.is_ascii_digit()) {
+                return Err(RaceSheetError::InvalidNumber {
+                    line,
+                    value: column.to_string(),
+                });
+            }
+
+            column.parse().map_err(|_| RaceSheetError::TooLarge {
+                line,
+                value: column.to_string(),
+            })
+        })
+        .collect::<Result<Vec<u64>, RaceSheetError>>()?;
+
+    Ok(Row {
+        line,
+        columns,
+        values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHEET: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+    #[test]
+    fn test_from_str() {
+        let expected = RaceSheet {
+            races: vec![
+                Race::from((7, 9)),
+                Race::from((15, 40)),
+                Race::from((30, 200)),
+            ],
+            kerned_race: Ok(Race::from((71_530, 940_200))),
+        };
+
+        let result: RaceSheet = SHEET.parse().unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_str_headers() {
+        assert_eq!(
+            "Distance: 7\nTime: 9".parse::<RaceSheet>(),
+            Err(RaceSheetError::UnexpectedHeader {
+                line: 1,
+                expected: "Time",
+                found: "Distance".to_string(),
+            })
+        );
+        assert_eq!(
+            "Time: 7\n\n9".parse::<RaceSheet>(),
+            Err(RaceSheetError::UnexpectedHeader {
+                line: 3,
+                expected: "Distance",
+                found: "9".to_string(),
+            })
+        );
+        assert_eq!(
+            "Time: 7\n".parse::<RaceSheet>(),
+            Err(RaceSheetError::MissingLine { header: "Distance" })
+        );
+        assert_eq!(
+            format!("{SHEET}Time: 1").parse::<RaceSheet>(),
+            Err(RaceSheetError::UnexpectedLine { line: 3 })
+        );
+    }
+
+    #[test]
+    fn test_from_str_columns() {
+        assert_eq!(
+            "Time: 7 15\nDistance: 9".parse::<RaceSheet>(),
+            Err(RaceSheetError::ColumnCountMismatch {
+                times: 2,
+                distances: 1,
+            })
+        );
+        assert_eq!(
+            "Time:\nDistance:".parse::<RaceSheet>(),
+            Err(RaceSheetError::NoRaces)
+        );
+        assert_eq!(
+            "Time: 7 -15\nDistance: 9 40".parse::<RaceSheet>(),
+            Err(RaceSheetError::InvalidNumber {
+                line: 1,
+                value: "-15".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_str_too_large() {
+        assert_eq!(
+            "Time: 7\nDistance: 18446744073709551616".parse::<RaceSheet>(),
+            Err(RaceSheetError::TooLarge {
+                line: 2,
+                value: "18446744073709551616".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_str_kerned_too_large() {
+        let result: RaceSheet = "Time: 1844674407 3709551616\nDistance: 9 40"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            result.races(),
+            [
+                Race::from((1_844_674_407, 9)),
+                Race::from((3_709_551_616, 40))
+            ]
+        );
+        assert_eq!(
+            result.kerned_race(),
+            Err(&RaceSheetError::TooLarge {
+                line: 1,
+                value: "18446744073709551616".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        let error = RaceSheetError::UnexpectedHeader {
+            line: 2,
+            expected: "Distance",
+            found: "Record".to_string(),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "Line 2: expected 'Distance:', found 'Record'"
+        );
+    }
+}
@@ -102,7 +102,7 @@ impl Hand {
                 3 => three_of_a_kind = Some(card),
                 2 if first_pair.is_none() => first_pair = Some(card),
                 2 if first_pair.is_some() => second_pair = Some(card),
-                _ => continue,
+                _ => {}
             }
         }
 
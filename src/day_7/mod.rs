@@ -1,16 +1,27 @@
 mod card;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::Solution;
 
-use card::Hand;
+pub use card::{Card, Hand, ScoreType};
 
-pub fn run() {
-    let input = to_string_vector("inputs/day_7.txt").expect("Something went wrong with Day 7!");
+pub struct Day7;
 
-    let hands: Vec<Hand> = input.iter().filter_map(|row| row.parse().ok()).collect();
+impl Solution for Day7 {
+    type Input = Vec<Hand>;
+    type Part1Answer = u32;
+    type Part2Answer = u32;
 
-    println!("Day 7 Part 1: {}", part_1(&hands));
-    println!("Day 7 Part 2: {}", part_2(&hands));
+    fn parse(&self, input: &[String]) -> Self::Input {
+        input.iter().filter_map(|row| row.parse().ok()).collect()
+    }
+
+    fn part_1(&self, input: &Self::Input) -> u32 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> u32 {
+        part_2(input)
+    }
 }
 
 fn part_1(hands: &[Hand]) -> u32 {
@@ -37,6 +48,8 @@ fn part_2(hands: &[Hand]) -> u32 {
 mod tests {
     use super::*;
 
+    use crate::util::file_reader::to_string_vector;
+
     #[test]
     fn test_part_1() {
         let input = to_string_vector("test_inputs/day_7.txt")
@@ -2,7 +2,10 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use std::convert::From;
 use std::str::FromStr;
 
+use crate::util::cycle;
+use crate::util::file_reader::to_sections;
 use crate::util::math;
+use crate::util::search;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Instruction {
@@ -28,11 +31,18 @@ pub struct Map {
 
 impl Map {
     pub fn new(input: &[String]) -> Self {
-        let instructions: VecDeque<Instruction> = input[0].chars().map(Instruction::from).collect();
+        let sections = to_sections(input);
 
-        let network = input
+        let instructions: VecDeque<Instruction> = sections[0]
+            .lines
+            .concat()
+            .chars()
+            .map(Instruction::from)
+            .collect();
+
+        let network = sections[1..]
             .iter()
-            .skip(2)
+            .flat_map(|section| section.lines)
             .filter_map(|row| row.parse::<Node>().ok())
             .map(|node| (node.label.clone(), node))
             .collect();
@@ -43,25 +53,30 @@ impl Map {
         }
     }
 
+    /// The number of steps from `start_label` to `end_label` following the instructions.
+    ///
+    /// # Panics
+    ///
+    /// If `end_label` can't be reached from `start_label`.
+    #[must_use]
     pub fn steps_between(&self, start_label: &str, end_label: &str) -> u32 {
-        let mut steps: u32 = 0;
-
-        let mut instructions_copy = self.instructions.clone();
-        let mut current_node = self.network.get(start_label);
-
-        while current_node.map_or("", |node| node.label.as_str()) != end_label {
-            steps += 1;
-
-            current_node = instructions_copy
-                .front()
-                .and_then(|instruction| self.get_child_node(current_node, *instruction));
-
-            instructions_copy.rotate_left(1);
-        }
-
-        steps
+        let path = search::bfs(
+            (start_label, 0),
+            |&state| self.take_instruction(state),
+            |&(label, _)| label == end_label,
+        )
+        .expect("End should be reachable from the start!");
+
+        u32::try_from(path.cost).expect("Steps should fit in u32!")
     }
 
+    /// The number of steps until every ghost, starting from each label ending with
+    /// `start_ends_with`, is at once on a label ending with `end_ends_with`.
+    ///
+    /// # Panics
+    ///
+    /// If there are no starting labels, or the ghosts never end up on an ending label together.
+    #[must_use]
     pub fn steps_between_all(&self, start_ends_with: char, end_ends_with: char) -> usize {
         let starting_nodes: Vec<String> = self
             .network
@@ -86,43 +101,41 @@ impl Map {
     }
 
     fn get_endings_info(&self, start_label: &str, end_ends_with: char) -> EndingsInfo {
-        let mut steps = 0;
-
-        let mut instructions_copy: VecDeque<(usize, Instruction)> =
-            self.instructions.iter().copied().enumerate().collect();
-        let mut seen_nodes: HashMap<(String, usize), usize> = HashMap::new();
-        let mut current_node = self.network.get(start_label).unwrap();
-
-        let mut instruction_index = instructions_copy.len() - 1;
-
-        while !seen_nodes.contains_key(&(current_node.label.clone(), instruction_index)) {
-            seen_nodes.insert((current_node.label.clone(), instruction_index), steps);
-
-            steps += 1;
-
-            let instruction = instructions_copy.front().unwrap().1;
+        let cycle = cycle::find_cycle((start_label, 0), |&state| {
+            self.take_instruction(state)
+                .expect("Every node should have both children!")
+        });
 
-            instruction_index = instructions_copy.front().unwrap().0;
-
-            current_node = self
-                .get_child_node(Some(current_node), instruction)
-                .unwrap();
-
-            instructions_copy.rotate_left(1);
-        }
-
-        let target_endings_steps: Vec<usize> = seen_nodes
+        let target_endings_steps: Vec<usize> = cycle
+            .states
             .iter()
-            .filter(|((label, _), _)| label.ends_with(end_ends_with))
-            .map(|(_, step)| *step)
+            .enumerate()
+            .filter(|(_, (label, _))| label.ends_with(end_ends_with))
+            .map(|(step, _)| step)
             .collect();
 
-        let repeating_start = *seen_nodes
-            .get(&(current_node.label.clone(), instruction_index))
-            .unwrap();
-        let repeating_length = steps - repeating_start;
+        EndingsInfo::new(
+            &target_endings_steps,
+            cycle.prefix_length,
+            cycle.cycle_length,
+        )
+    }
 
-        EndingsInfo::new(&target_endings_steps, repeating_start, repeating_length)
+    /// Follows the instruction at `instruction_index` from the node labelled `label`, giving the
+    /// child's label and the index of the instruction after.
+    fn take_instruction<'a>(
+        &'a self,
+        (label, instruction_index): (&str, usize),
+    ) -> Option<(&'a str, usize)> {
+        let instruction = self.instructions[instruction_index];
+
+        self.get_child_node(self.network.get(label), instruction)
+            .map(|child| {
+                (
+                    child.label.as_str(),
+                    (instruction_index + 1) % self.instructions.len(),
+                )
+            })
     }
 
     fn get_child_node(&self, node_opt: Option<&Node>, instruction: Instruction) -> Option<&Node> {
@@ -140,6 +153,7 @@ pub struct Node {
 }
 
 impl Node {
+    #[must_use]
     pub fn get_child_label_from(&self, instruction: Instruction) -> String {
         match instruction {
             Instruction::Left => self.left_label.clone(),
@@ -231,34 +245,41 @@ impl EndingsInfo {
             .map_or(step, |value| (value % repeating_length) + repeating_start)
     }
 
-    #[allow(clippy::cast_sign_loss)]
+    /// The first step that is both `first_step` plus a multiple of `first_repeated` and
+    /// `second_step` plus a multiple of `second_repeated`, where a step that doesn't repeat has
+    /// `repeated` of 0.
     fn first_step_intersection(
         first_step: usize,
         first_repeated: usize,
         second_step: usize,
         second_repeated: usize,
     ) -> Option<usize> {
-        let first_cant_intersect = (first_repeated == 0) && (second_repeated != 0) && (second_step > first_step);
-        let second_cant_intersect = (second_repeated == 0) && (first_repeated != 0) && (first_step > second_step);
-        let neither_intersect = (first_repeated == 0) && (second_repeated == 0) && (first_step != second_step);
-
-        if first_cant_intersect || second_cant_intersect || neither_intersect {
-            None
-        } else if (first_repeated != 0) || (second_repeated != 0) {
-            let step_diff =
-                i64::try_from(second_step).unwrap() - i64::try_from(first_step).unwrap();
-
-            let repeations = math::min_positive_linear_diophantine(
-                i64::try_from(first_repeated).unwrap(),
-                -i64::try_from(second_repeated).unwrap(),
-                step_diff,
-            );
-
-            repeations
-                .map(|(first_r, _)| first_step + usize::try_from(first_r).unwrap() * first_repeated)
-        } else {
-            Some(first_step)
-        } 
+        match (first_repeated, second_repeated) {
+            (0, 0) => (first_step == second_step).then_some(first_step),
+            (0, _) => {
+                Self::lands_on(first_step, second_step, second_repeated).then_some(first_step)
+            }
+            (_, 0) => {
+                Self::lands_on(second_step, first_step, first_repeated).then_some(second_step)
+            }
+            _ => {
+                let to_i64 = |value: usize| i64::try_from(value).expect("Step should fit in i64!");
+
+                let (remainder, modulus) = math::crt(&[
+                    (to_i64(first_step), to_i64(first_repeated)),
+                    (to_i64(second_step), to_i64(second_repeated)),
+                ])?;
+
+                let min_step = to_i64(first_step.max(second_step));
+
+                usize::try_from(min_step + (remainder - min_step).rem_euclid(modulus)).ok()
+            }
+        }
+    }
+
+    fn lands_on(step: usize, start: usize, repeated: usize) -> bool {
+        step.checked_sub(start)
+            .is_some_and(|value| value % repeated == 0)
     }
 }
 
@@ -482,4 +503,14 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_endings_first_step_intersection_edge_cases() {
+        assert_eq!(EndingsInfo::first_step_intersection(4, 3, 4, 5), Some(4));
+        assert_eq!(EndingsInfo::first_step_intersection(7, 0, 1, 3), Some(7));
+        assert_eq!(EndingsInfo::first_step_intersection(7, 0, 8, 3), None);
+        assert_eq!(EndingsInfo::first_step_intersection(2, 4, 5, 0), None);
+        assert_eq!(EndingsInfo::first_step_intersection(3, 0, 3, 0), Some(3));
+        assert_eq!(EndingsInfo::first_step_intersection(1, 2, 2, 4), None);
+    }
 }
@@ -1,16 +1,27 @@
 mod map;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::Solution;
 
-use map::Map;
+pub use map::{Instruction, Map, Node};
 
-pub fn run() {
-    let input = to_string_vector("inputs/day_8.txt").expect("Something went wrong with Day 8!");
+pub struct Day8;
 
-    let map = Map::new(&input);
+impl Solution for Day8 {
+    type Input = Map;
+    type Part1Answer = u32;
+    type Part2Answer = usize;
 
-    println!("Day 8 Part 1: {}", part_1(&map));
-    println!("Day 8 Part 2: {}", part_2(&map));
+    fn parse(&self, input: &[String]) -> Self::Input {
+        Map::new(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> u32 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> usize {
+        part_2(input)
+    }
 }
 
 fn part_1(map: &Map) -> u32 {
@@ -25,6 +36,8 @@ fn part_2(map: &Map) -> usize {
 mod tests {
     use super::*;
 
+    use crate::util::file_reader::to_string_vector;
+
     #[test]
     fn test_part_1() {
         let input = to_string_vector("test_inputs/day_8.txt")
@@ -1,16 +1,27 @@
 mod oasis;
 
-use crate::util::file_reader::to_string_vector;
+use crate::solution::Solution;
 
-use oasis::Oasis;
+pub use oasis::{Oasis, Report};
 
-pub fn run() {
-    let input = to_string_vector("inputs/day_9.txt").expect("Something went wrong with Day 7!");
+pub struct Day9;
 
-    let oasis = Oasis::new(&input);
+impl Solution for Day9 {
+    type Input = Oasis;
+    type Part1Answer = i32;
+    type Part2Answer = i32;
 
-    println!("Day 9 Part 1: {}", part_1(&oasis));
-    println!("Day 9 Part 2: {}", part_2(&oasis));
+    fn parse(&self, input: &[String]) -> Self::Input {
+        Oasis::new(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> i32 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> i32 {
+        part_2(input)
+    }
 }
 
 fn part_1(oasis: &Oasis) -> i32 {
@@ -25,6 +36,8 @@ fn part_2(oasis: &Oasis) -> i32 {
 mod tests {
     use super::*;
 
+    use crate::util::file_reader::to_string_vector;
+
     #[test]
     fn test_part_1() {
         let input = to_string_vector("test_inputs/day_9.txt")
@@ -1,14 +1,28 @@
 use std::str::FromStr;
 
+use crate::util::file_reader::to_number_rows;
+
 #[derive(Debug, PartialEq)]
 pub struct Oasis {
     reports: Vec<Report>,
 }
 
 impl Oasis {
+    /// Creates a new Oasis with a report per line
+    ///
+    /// # Panics
+    ///
+    /// If a report has a value that isn't a number.
+    #[must_use]
     pub fn new(input: &[String]) -> Self {
+        let value_histories =
+            to_number_rows::<i32>(input).expect("Report values should be numbers!");
+
         Oasis {
-            reports: input.iter().filter_map(|row| row.parse().ok()).collect(),
+            reports: value_histories
+                .iter()
+                .map(|value_history| Report::new(value_history))
+                .collect(),
         }
     }
 
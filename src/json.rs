@@ -0,0 +1,131 @@
+use std::fmt;
+
+/// A JSON value, serialized compactly by its `Display` impl.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Integer(i128),
+    String(String),
+    Object(Vec<(String, Json)>),
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl From<u32> for Json {
+    fn from(value: u32) -> Self {
+        Json::Integer(i128::from(value))
+    }
+}
+
+impl From<u64> for Json {
+    fn from(value: u64) -> Self {
+        Json::Integer(i128::from(value))
+    }
+}
+
+impl<T> From<Option<T>> for Json
+where
+    T: Into<Json>,
+{
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(value) => write!(f, "{value}"),
+            Json::Integer(value) => write!(f, "{value}"),
+            Json::String(value) => write_escaped(f, value),
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index != 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write_escaped(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_escaped(f: &mut fmt::Formatter, value: &str) -> fmt::Result {
+    write!(f, "\"")?;
+
+    for c in value.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", u32::from(c))?,
+            c => write!(f, "{c}")?,
+        }
+    }
+
+    write!(f, "\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_scalars() {
+        assert_eq!(Json::Null.to_string(), "null");
+        assert_eq!(Json::from(true).to_string(), "true");
+        assert_eq!(Json::from(42_u32).to_string(), "42");
+        assert_eq!(Json::Integer(-7).to_string(), "-7");
+        assert_eq!(Json::from("abc").to_string(), "\"abc\"");
+        assert_eq!(Json::from(None::<u32>).to_string(), "null");
+    }
+
+    #[test]
+    fn test_display_escaped_string() {
+        let value = Json::from("say \"hi\"\\\n\t\u{1}");
+
+        let expected = r#""say \"hi\"\\\n\t\u0001""#;
+
+        assert_eq!(value.to_string(), expected);
+    }
+
+    #[test]
+    fn test_display_nested() {
+        let value = Json::Object(vec![
+            ("day".to_string(), Json::from(1_u32)),
+            ("answer".to_string(), Json::from("1")),
+            ("error".to_string(), Json::Null),
+            ("empty".to_string(), Json::Object(Vec::new())),
+        ]);
+
+        let expected = r#"{"day":1,"answer":"1","error":null,"empty":{}}"#;
+
+        assert_eq!(value.to_string(), expected);
+    }
+}
@@ -0,0 +1,40 @@
+pub mod bench;
+pub mod cli;
+pub mod day_1;
+pub mod day_10;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+pub mod json;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod table;
+pub mod util;
+pub mod verify;
+
+use solution::Registry;
+
+/// Every solved day, keyed by its day number.
+#[must_use]
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry.register(1, day_1::Day1::default());
+    registry.register(2, day_2::Day2);
+    registry.register(3, day_3::Day3);
+    registry.register(4, day_4::Day4);
+    registry.register(5, day_5::Day5);
+    registry.register(6, day_6::Day6);
+    registry.register(7, day_7::Day7);
+    registry.register(8, day_8::Day8);
+    registry.register(9, day_9::Day9);
+    registry.register(10, day_10::Day10);
+
+    registry
+}
@@ -1,69 +1,326 @@
-pub mod util;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Instant;
 
-mod day_1;
-mod day_10;
-mod day_2;
-mod day_3;
-mod day_4;
-mod day_5;
-mod day_6;
-mod day_7;
-mod day_8;
-mod day_9;
+use advent_of_code_2023::cli::{self, Command, OutputFormat};
+use advent_of_code_2023::day_1::{Day1, Day1Options};
+use advent_of_code_2023::runner::{self, PartResult};
+use advent_of_code_2023::solution::{Part, Registry};
+use advent_of_code_2023::util::file_reader::{to_string_vector, InputSource};
+use advent_of_code_2023::{bench, registry, scaffold, verify};
 
-use std::io::{self, Write};
+const DEFAULT_ANSWERS_FILE: &str = "answers.txt";
 
 fn print_seperator() {
     println!("-------------------------------------");
 }
 
-fn run_day(day: u32) {
-    match day {
-        1 => day_1::run(),
-        2 => day_2::run(),
-        3 => day_3::run(),
-        4 => day_4::run(),
-        5 => day_5::run(),
-        6 => day_6::run(),
-        7 => day_7::run(),
-        8 => day_8::run(),
-        9 => day_9::run(),
-        10 => day_10::run(),
-        _ => unimplemented!("I haven't done that day yet :("),
+fn default_input_file(day: u32) -> String {
+    format!("inputs/day_{day}.txt")
+}
+
+fn default_input(day: u32) -> InputSource {
+    InputSource::Path(default_input_file(day))
+}
+
+/// Reads the file's lines, describing any failure in a printable message.
+///
+/// # Errors
+///
+/// If the file couldn't be read.
+fn read_lines(file_name: &str) -> Result<Vec<String>, String> {
+    to_string_vector(file_name).map_err(|error| error.to_string())
+}
+
+/// Registers day 1 again with the given options, printing the explanations it gives for `parts`.
+///
+/// # Errors
+///
+/// If day 1 couldn't be set up with the options.
+fn configure_day_1(
+    registry: &mut Registry,
+    options: &Day1Options,
+    parts: &[Part],
+    lines: &Result<Vec<String>, String>,
+) -> Result<(), String> {
+    let solution = Day1::from_options(options)?;
+
+    if let Ok(lines) = lines {
+        for explanation in solution.explanations(lines, parts) {
+            print_seperator();
+            print!("{explanation}");
+        }
+    }
+
+    registry.register(1, solution);
+
+    Ok(())
+}
+
+/// Runs the given day against the input's lines, or fails every part if they couldn't be read.
+fn run_day(
+    registry: &Registry,
+    day: u32,
+    parts: &[Part],
+    lines: &Result<Vec<String>, String>,
+) -> Vec<PartResult> {
+    let Some(solution) = registry.get(day) else {
+        return PartResult::failed(day, parts, &format!("I haven't done day {day} yet :("));
+    };
+
+    match lines {
+        Ok(lines) => runner::run_parts(day, solution, lines, parts),
+        Err(error) => PartResult::failed(day, parts, error),
+    }
+}
+
+/// Runs `part` (or both parts) of `day` on the input, printing the results in `format`.
+///
+/// # Errors
+///
+/// If day 1 couldn't be set up with its options, or any of the parts failed.
+fn run_requested_day(
+    registry: &mut Registry,
+    day: u32,
+    part: Option<Part>,
+    input: Option<InputSource>,
+    format: OutputFormat,
+    day_1: &Day1Options,
+) -> Result<(), String> {
+    let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+    let lines = input
+        .unwrap_or_else(|| default_input(day))
+        .read_lines()
+        .map_err(|error| error.to_string());
+
+    if day == 1 {
+        configure_day_1(registry, day_1, &parts, &lines)?;
+    }
+
+    let results = run_day(registry, day, &parts, &lines);
+
+    if format == OutputFormat::Text {
+        print_seperator();
+    }
+
+    print_results(&results, format);
+
+    if format == OutputFormat::Text {
+        print_seperator();
+    }
+
+    check_results(&results)
+}
+
+fn print_results(results: &[PartResult], format: OutputFormat) {
+    for result in results {
+        match (format, &result.answer) {
+            (OutputFormat::Json, _) => println!("{}", result.to_json()),
+            (OutputFormat::Text, Ok(answer)) => {
+                println!("Day {} Part {}: {answer}", result.day, result.part);
+            }
+            (OutputFormat::Text, Err(error)) => {
+                eprintln!("Day {} Part {} failed: {error}", result.day, result.part);
+            }
+        }
+    }
+}
+
+/// # Errors
+///
+/// If any of the parts failed.
+fn check_results(results: &[PartResult]) -> Result<(), String> {
+    let failures = results
+        .iter()
+        .filter(|result| result.answer.is_err())
+        .count();
+
+    if failures == 0 {
+        Ok(())
+    } else {
+        Err(format!("{failures} part(s) failed!"))
+    }
+}
+
+/// Verifies every registered day's answers for its default input against the answers file.
+///
+/// # Errors
+///
+/// If the answers file can't be parsed, or any answer is wrong or couldn't be computed.
+fn verify_answers(registry: &Registry, answers_file: &str) -> Result<(), String> {
+    let answers = verify::parse_answers(&read_lines(answers_file)?)?;
+
+    let checks = verify::verify(registry, &answers, |day| {
+        read_lines(&default_input_file(day))
+    });
+
+    println!("{}", verify::report(&checks));
+
+    let regressions = checks.iter().filter(|check| check.is_regression()).count();
+
+    if regressions == 0 {
+        Ok(())
+    } else {
+        Err(format!("{regressions} answer(s) didn't verify!"))
     }
 }
 
-/// Gets the user input.
+/// Benchmarks `day` (or every registered day) on its default input.
 ///
-/// # Panics
+/// # Errors
 ///
-/// If line couldn't be flushed and/or stdin couldn't be read/parsed.
-#[must_use]
-pub fn get_user_input() -> u32 {
-    let mut input_buffer = String::new();
+/// If the day isn't registered, a file can't be read or written, or any stage failed or
+/// regressed against the baseline.
+fn run_bench(
+    registry: &Registry,
+    day: Option<u32>,
+    iterations: usize,
+    baseline_file: Option<&str>,
+    threshold_percent: u32,
+    save_file: Option<&str>,
+) -> Result<(), String> {
+    let baseline = baseline_file
+        .map(|file| read_lines(file).and_then(|lines| bench::parse_baseline(&lines)))
+        .transpose()?;
+
+    let days: Vec<u32> = match day {
+        Some(day) if registry.get(day).is_some() => vec![day],
+        Some(day) => return Err(format!("I haven't done day {day} yet :(")),
+        None => registry.days().collect(),
+    };
 
-    io::stdout().flush().expect("Could not flush stdout!");
+    let mut measurements = Vec::new();
 
-    io::stdin()
-        .read_line(&mut input_buffer)
-        .expect("Failed to read user input!");
+    for day in days {
+        let solution = registry.get(day).expect("Day should be registered!");
 
-    input_buffer
-        .trim()
-        .parse::<u32>()
-        .expect("Failed to parse user_input!")
+        measurements.extend(bench::bench_day(day, solution, iterations, || {
+            read_lines(&default_input_file(day))
+        }));
+    }
+
+    println!(
+        "{}",
+        bench::report(&measurements, baseline.as_ref(), threshold_percent)
+    );
+
+    if let Some(save_file) = save_file {
+        let mut contents = bench::to_baseline_lines(&measurements).join("\n");
+        contents.push('\n');
+
+        fs::write(save_file, contents)
+            .map_err(|error| format!("Couldn't save baseline to {save_file}: {error}"))?;
+    }
+
+    let failures = measurements
+        .iter()
+        .filter(|measurement| measurement.stats.is_err())
+        .count();
+
+    let regressions = baseline.map_or(0, |baseline| {
+        measurements
+            .iter()
+            .filter(|measurement| measurement.is_regression(&baseline, threshold_percent))
+            .count()
+    });
+
+    if failures > 0 {
+        Err(format!("{failures} stage(s) failed!"))
+    } else if regressions > 0 {
+        Err(format!(
+            "{regressions} stage(s) regressed by more than {threshold_percent}%!"
+        ))
+    } else {
+        Ok(())
+    }
 }
 
-fn main() {
-    print_seperator();
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match cli::parse_args(&args) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{error}");
+            eprintln!("{}", cli::USAGE);
+
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut registry = registry();
 
-    print!("Please choose a day to run (1-25): ");
+    let result = match command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            day_1,
+        } => run_requested_day(&mut registry, day, part, input, format, &day_1),
+        Command::RunAll { part, format } => {
+            let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
 
-    let input = get_user_input();
+            let start = Instant::now();
+            let results = runner::run_all(&registry, &parts, |day| {
+                read_lines(&default_input_file(day))
+            });
+            let wall_time = start.elapsed();
 
-    print_seperator();
+            match format {
+                OutputFormat::Text => println!("{}", runner::summary(&results, wall_time)),
+                OutputFormat::Json => print_results(&results, format),
+            }
 
-    run_day(input);
+            check_results(&results)
+        }
+        Command::Verify { answers_file } => verify_answers(
+            &registry,
+            &answers_file.unwrap_or_else(|| DEFAULT_ANSWERS_FILE.to_string()),
+        ),
+        Command::Bench {
+            day,
+            iterations,
+            baseline_file,
+            threshold_percent,
+            save_file,
+        } => run_bench(
+            &registry,
+            day,
+            iterations,
+            baseline_file.as_deref(),
+            threshold_percent,
+            save_file.as_deref(),
+        ),
+        Command::NewDay { day, struct_name } => {
+            scaffold::new_day(Path::new("."), day, &struct_name).map(|changed| {
+                for path in changed {
+                    println!("Wrote {}", path.display());
+                }
+            })
+        }
+        Command::List => {
+            for day in registry.days() {
+                println!("Day {day}");
+            }
 
-    print_seperator();
+            Ok(())
+        }
+        Command::Help => {
+            println!("{}", cli::USAGE);
+
+            Ok(())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,325 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::json::Json;
+use crate::solution::{panic_message, Answer, DynSolution, Part, Registry};
+use crate::table;
+
+/// The outcome of solving one part of a day.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PartResult {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Result<Answer, String>,
+    pub duration: Option<Duration>,
+}
+
+impl PartResult {
+    /// A result for each of `parts` failing with the same error.
+    #[must_use]
+    pub fn failed(day: u32, parts: &[Part], error: &str) -> Vec<Self> {
+        parts
+            .iter()
+            .map(|&part| PartResult {
+                day,
+                part,
+                answer: Err(error.to_string()),
+                duration: None,
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn to_json(&self) -> Json {
+        let (answer, type_name, error) = match &self.answer {
+            Ok(answer) => (
+                Json::from(answer.value.as_str()),
+                Json::from(answer.type_name),
+                Json::Null,
+            ),
+            Err(error) => (Json::Null, Json::Null, Json::from(error.as_str())),
+        };
+
+        let part_number: u32 = match self.part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+
+        let duration_ns = self
+            .duration
+            .map(|duration| u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX));
+
+        Json::Object(vec![
+            ("day".to_string(), Json::from(self.day)),
+            ("part".to_string(), Json::from(part_number)),
+            ("answer".to_string(), answer),
+            ("type".to_string(), type_name),
+            ("duration_ns".to_string(), Json::from(duration_ns)),
+            ("error".to_string(), error),
+        ])
+    }
+}
+
+/// Parses `input` once and solves each of `parts` from it, timing each part.
+///
+/// A panic while parsing fails every part, and a panic while solving fails just that part.
+pub fn run_parts(
+    day: u32,
+    solution: &dyn DynSolution,
+    input: &[String],
+    parts: &[Part],
+) -> Vec<PartResult> {
+    let parsed_input = match panic::catch_unwind(AssertUnwindSafe(|| solution.parse_input(input))) {
+        Ok(parsed_input) => parsed_input,
+        Err(payload) => return PartResult::failed(day, parts, &panic_message(payload.as_ref())),
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+                solution.solve(parsed_input.as_ref(), part)
+            }));
+            let duration = start.elapsed();
+
+            match answer {
+                Ok(answer) => PartResult {
+                    day,
+                    part,
+                    answer: Ok(answer),
+                    duration: Some(duration),
+                },
+                Err(payload) => PartResult {
+                    day,
+                    part,
+                    answer: Err(panic_message(payload.as_ref())),
+                    duration: None,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Runs every registered day on its own thread, solving only `parts` of each.
+///
+/// Results are returned in day order, whichever day finishes first.
+///
+/// # Panics
+///
+/// If `read_input` panics, since only panics while solving are caught.
+pub fn run_all<F>(registry: &Registry, parts: &[Part], read_input: F) -> Vec<PartResult>
+where
+    F: Fn(u32) -> Result<Vec<String>, String> + Sync,
+{
+    let read_input = &read_input;
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = registry
+            .iter()
+            .map(|(day, solution)| {
+                scope.spawn(move || match read_input(day) {
+                    Ok(input) => run_parts(day, solution, &input, parts),
+                    Err(error) => PartResult::failed(day, parts, &error),
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .expect("Panics should be caught by run_parts!")
+            })
+            .collect()
+    })
+}
+
+/// Formats the results as an aligned table, followed by the totals.
+///
+/// `wall_time` is how long the whole run took, which is less than the summed part times when
+/// days run in parallel.
+#[must_use]
+pub fn summary(results: &[PartResult], wall_time: Duration) -> String {
+    let rows: Vec<[String; 4]> = results
+        .iter()
+        .map(|result| {
+            [
+                result.day.to_string(),
+                result.part.to_string(),
+                match &result.answer {
+                    Ok(answer) => answer.value.clone(),
+                    Err(error) => format!("FAILED: {error}"),
+                },
+                result
+                    .duration
+                    .map_or("-".to_string(), |duration| format!("{duration:.2?}")),
+            ]
+        })
+        .collect();
+
+    let table = table::format_table(["Day", "Part", "Answer", "Time"], &rows);
+
+    let failures = results
+        .iter()
+        .filter(|result| result.answer.is_err())
+        .count();
+    let solve_time: Duration = results.iter().filter_map(|result| result.duration).sum();
+
+    format!(
+        "{table}\nTotal: {} part(s), {failures} failed, {solve_time:.2?} solving, {wall_time:.2?} wall time",
+        results.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::solution::test_support::{Broken, Summer};
+
+    fn read_input(day: u32) -> Result<Vec<String>, String> {
+        match day {
+            1 | 2 => Ok(vec!["4".to_string(), "5".to_string()]),
+            3 => Ok(vec!["four".to_string()]),
+            _ => Err(format!("No input for day {day}!")),
+        }
+    }
+
+    #[test]
+    fn test_run_parts() {
+        let input = ["4".to_string(), "5".to_string()];
+
+        let result = run_parts(2, &Summer, &input, &[Part::Two]);
+
+        let expected_answer = Answer {
+            value: "2".to_string(),
+            type_name: "usize",
+        };
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].day, 2);
+        assert_eq!(result[0].part, Part::Two);
+        assert_eq!(result[0].answer, Ok(expected_answer));
+        assert!(result[0].duration.is_some());
+    }
+
+    #[test]
+    fn test_run_parts_panic() {
+        let input = ["4".to_string()];
+
+        let result = run_parts(1, &Broken, &input, &Part::ALL);
+
+        assert_eq!(
+            result[0]
+                .answer
+                .as_ref()
+                .map(|answer| answer.value.as_str()),
+            Ok("4")
+        );
+        assert_eq!(
+            result[1].answer,
+            Err("Panicked: Part 2 isn't done!".to_string())
+        );
+        assert!(result[1].duration.is_none());
+    }
+
+    #[test]
+    fn test_run_all() {
+        let mut registry = Registry::new();
+
+        registry.register(1, Summer);
+        registry.register(2, Broken);
+        registry.register(3, Summer);
+        registry.register(4, Summer);
+
+        let expected = vec![
+            (1, Part::One, Ok("9".to_string())),
+            (1, Part::Two, Ok("2".to_string())),
+            (2, Part::One, Ok("4".to_string())),
+            (2, Part::Two, Err("Panicked: Part 2 isn't done!".to_string())),
+            (3, Part::One, Err("Panicked: called `Result::unwrap()` on an `Err` value: ParseIntError { kind: InvalidDigit }".to_string())),
+            (3, Part::Two, Err("Panicked: called `Result::unwrap()` on an `Err` value: ParseIntError { kind: InvalidDigit }".to_string())),
+            (4, Part::One, Err("No input for day 4!".to_string())),
+            (4, Part::Two, Err("No input for day 4!".to_string())),
+        ];
+
+        let result: Vec<(u32, Part, Result<String, String>)> =
+            run_all(&registry, &Part::ALL, read_input)
+                .into_iter()
+                .map(|result| {
+                    (
+                        result.day,
+                        result.part,
+                        result.answer.map(|answer| answer.value),
+                    )
+                })
+                .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut results = PartResult::failed(12, &[Part::One], "Not done yet!");
+
+        results.insert(
+            0,
+            PartResult {
+                day: 1,
+                part: Part::Two,
+                answer: Ok(Answer {
+                    value: "281".to_string(),
+                    type_name: "CalibrationValue",
+                }),
+                duration: Some(Duration::from_micros(1_500)),
+            },
+        );
+
+        let expected = "\
+Day | Part | Answer                | Time
+1   | 2    | 281                   | 1.50ms
+12  | 1    | FAILED: Not done yet! | -
+Total: 2 part(s), 1 failed, 1.50ms solving, 2.00ms wall time";
+
+        let result = summary(&results, Duration::from_millis(2));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_to_json() {
+        let result = PartResult {
+            day: 1,
+            part: Part::One,
+            answer: Ok(Answer {
+                value: "142".to_string(),
+                type_name: "CalibrationValue",
+            }),
+            duration: Some(Duration::from_nanos(1_500)),
+        };
+
+        let expected = r#"{"day":1,"part":1,"answer":"142","type":"CalibrationValue","duration_ns":1500,"error":null}"#;
+
+        assert_eq!(result.to_json().to_string(), expected);
+    }
+
+    #[test]
+    fn test_failed_to_json() {
+        let result = PartResult::failed(12, &Part::ALL, "Not done yet!");
+
+        let expected = [
+            r#"{"day":12,"part":1,"answer":null,"type":null,"duration_ns":null,"error":"Not done yet!"}"#,
+            r#"{"day":12,"part":2,"answer":null,"type":null,"duration_ns":null,"error":"Not done yet!"}"#,
+        ];
+
+        let result_json: Vec<String> = result
+            .iter()
+            .map(|part_result| part_result.to_json().to_string())
+            .collect();
+
+        assert_eq!(result_json, expected);
+    }
+}
@@ -0,0 +1,331 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The file declaring the day modules and registering them with the runner.
+const REGISTRY_FILE: &str = "src/lib.rs";
+
+const DAY_TEMPLATE: &str = "templates/day_template.txt";
+const MOD_TEMPLATE: &str = "templates/mod_template.txt";
+
+/// Creates `src/day_<day>/` from the templates, empty input files and registers the new day.
+///
+/// All paths are relative to `root`. Returns the files that were created or changed.
+///
+/// # Errors
+///
+/// If the struct name isn't `UpperCamelCase`, the day already exists, or a file can't be read
+/// or written.
+pub fn new_day(root: &Path, day: u32, struct_name: &str) -> Result<Vec<PathBuf>, String> {
+    if !is_upper_camel_case(struct_name) {
+        return Err(format!("'{struct_name}' should be an UpperCamelCase name!"));
+    }
+
+    let day_dir = root.join(format!("src/day_{day}"));
+
+    if day_dir.exists() {
+        return Err(format!("{} already exists!", day_dir.display()));
+    }
+
+    let module = to_snake_case(struct_name);
+
+    let day_file = render(&read(&root.join(DAY_TEMPLATE))?, day, struct_name);
+    let mod_file = render(&read(&root.join(MOD_TEMPLATE))?, day, struct_name);
+
+    let registry_path = root.join(REGISTRY_FILE);
+    let registry_file = insert_registration(&insert_module(&read(&registry_path)?, day)?, day)?;
+
+    fs::create_dir_all(&day_dir).map_err(|error| describe_write(&day_dir, &error))?;
+
+    let mut changed = vec![
+        write(&day_dir.join("mod.rs"), &day_file)?,
+        write(&day_dir.join(format!("{module}.rs")), &mod_file)?,
+    ];
+
+    for input_dir in ["inputs", "test_inputs"] {
+        let input_path = root.join(input_dir).join(format!("day_{day}.txt"));
+
+        if !input_path.exists() {
+            changed.push(write(&input_path, "")?);
+        }
+    }
+
+    changed.push(write(&registry_path, &registry_file)?);
+
+    Ok(changed)
+}
+
+/// Fills in the `{day}`, `{struct}` and `{module}` placeholders of a template.
+fn render(template: &str, day: u32, struct_name: &str) -> String {
+    template
+        .replace("{day}", &day.to_string())
+        .replace("{struct}", struct_name)
+        .replace("{module}", &to_snake_case(struct_name))
+}
+
+fn is_upper_camel_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut result = String::new();
+
+    for (index, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && index != 0 {
+            result.push('_');
+        }
+
+        result.push(c.to_ascii_lowercase());
+    }
+
+    result
+}
+
+/// Adds `mod day_<day>;` in order among the other day module declarations, with the same
+/// visibility as them.
+///
+/// # Errors
+///
+/// If the day is already declared, or there aren't any day modules to add it next to.
+fn insert_module(source: &str, day: u32) -> Result<String, String> {
+    let module = format!("day_{day}");
+
+    let lines: Vec<&str> = source.split_inclusive('\n').collect();
+
+    let day_module_indices: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| module_name(line).is_some_and(|name| name.starts_with("day_")))
+        .map(|(index, _)| index)
+        .collect();
+
+    if lines
+        .iter()
+        .any(|line| module_name(line) == Some(module.as_str()))
+    {
+        return Err(format!("Day {day} is already declared!"));
+    }
+
+    let Some(&last_index) = day_module_indices.last() else {
+        return Err("Couldn't find any day modules to declare the new day next to!".to_string());
+    };
+
+    let visibility = lines[last_index]
+        .split_once("mod ")
+        .map_or("", |(visibility, _)| visibility);
+    let declaration = format!("{visibility}mod {module};");
+
+    let insert_index = day_module_indices
+        .iter()
+        .find(|&&index| module_name(lines[index]) > Some(module.as_str()))
+        .map_or(last_index + 1, |&index| index);
+
+    Ok(insert_line(&lines, insert_index, &declaration))
+}
+
+/// Adds `registry.register(<day>, day_<day>::Day<day>);` in order among the other registrations.
+///
+/// # Errors
+///
+/// If there aren't any registrations to add it next to.
+fn insert_registration(source: &str, day: u32) -> Result<String, String> {
+    let lines: Vec<&str> = source.split_inclusive('\n').collect();
+
+    let registrations: Vec<(usize, Option<u32>)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            line.trim_start()
+                .strip_prefix("registry.register(")
+                .map(|rest| {
+                    let registered_day = rest
+                        .split_once(',')
+                        .and_then(|(registered_day, _)| registered_day.trim().parse().ok());
+
+                    (index, registered_day)
+                })
+        })
+        .collect();
+
+    let Some(&(last_index, _)) = registrations.last() else {
+        return Err("Couldn't find any registrations to register the new day next to!".to_string());
+    };
+
+    let insert_index = registrations
+        .iter()
+        .find(|(_, registered_day)| registered_day.is_some_and(|registered| registered > day))
+        .map_or(last_index + 1, |&(index, _)| index);
+
+    let indent: String = lines[last_index]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+
+    Ok(insert_line(
+        &lines,
+        insert_index,
+        &format!("{indent}registry.register({day}, day_{day}::Day{day});"),
+    ))
+}
+
+/// The name declared by a `mod <name>;` or `pub mod <name>;` line.
+fn module_name(line: &str) -> Option<&str> {
+    line.trim_end()
+        .trim_start_matches("pub ")
+        .strip_prefix("mod ")?
+        .strip_suffix(';')
+}
+
+fn insert_line(lines: &[&str], index: usize, line: &str) -> String {
+    let line_ending = if lines.first().is_some_and(|line| line.ends_with("\r\n")) {
+        "\r\n"
+    } else {
+        "\n"
+    };
+
+    let mut result: String = lines[..index].concat();
+
+    result.push_str(line);
+    result.push_str(line_ending);
+    result.push_str(&lines[index..].concat());
+
+    result
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("Couldn't read {}: {error}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<PathBuf, String> {
+    fs::write(path, contents).map_err(|error| describe_write(path, &error))?;
+
+    Ok(path.to_path_buf())
+}
+
+fn describe_write(path: &Path, error: &std::io::Error) -> String {
+    format!("Couldn't write {}: {error}", path.display())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+
+    const REGISTRY: &str = "\
+mod day_1;
+mod day_10;
+mod day_2;
+mod json;
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry.register(1, day_1::Day1);
+    registry.register(2, day_2::Day2);
+    registry.register(10, day_10::Day10);
+
+    registry
+}
+";
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("CalibrationValue"), "calibration_value");
+        assert_eq!(to_snake_case("Race"), "race");
+        assert_eq!(to_snake_case("Point2d"), "point2d");
+    }
+
+    #[test]
+    fn test_render() {
+        let template = "mod {module};\nuse {module}::{struct};\npub struct Day{day};";
+
+        let expected = "mod scratch_card;\nuse scratch_card::ScratchCard;\npub struct Day4;";
+
+        let result = render(template, 4, "ScratchCard");
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_insert_module() {
+        let result = insert_module(REGISTRY, 3).unwrap();
+
+        assert!(result.starts_with("mod day_1;\nmod day_10;\nmod day_2;\nmod day_3;\nmod json;\n"));
+        assert!(insert_module(&result, 3).is_err());
+        assert!(insert_module(REGISTRY, 11)
+            .unwrap()
+            .starts_with("mod day_1;\nmod day_10;\nmod day_11;\nmod day_2;\n"));
+        assert!(insert_module("mod json;\n", 3).is_err());
+    }
+
+    #[test]
+    fn test_insert_module_pub() {
+        let expected = "pub mod day_1;\npub mod day_2;\npub mod json;\n";
+
+        let result = insert_module("pub mod day_1;\npub mod json;\n", 2).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_insert_module_crlf() {
+        let expected = "mod day_1;\r\nmod day_2;\r\n";
+
+        let result = insert_module("mod day_1;\r\n", 2).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_insert_registration() {
+        let result_3 = insert_registration(REGISTRY, 3).unwrap();
+        let result_11 = insert_registration(REGISTRY, 11).unwrap();
+
+        assert!(result_3.contains(
+            "    registry.register(2, day_2::Day2);
+    registry.register(3, day_3::Day3);
+    registry.register(10, day_10::Day10);"
+        ));
+        assert!(result_11.contains(
+            "    registry.register(10, day_10::Day10);
+    registry.register(11, day_11::Day11);
+"
+        ));
+        assert!(insert_registration("mod day_1;\n", 3).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc_2023_scaffold_{}", std::process::id()));
+
+        for dir in ["src", "templates", "inputs", "test_inputs"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+
+        fs::write(root.join(REGISTRY_FILE), REGISTRY).unwrap();
+        fs::write(root.join(DAY_TEMPLATE), "pub struct Day{day};\n").unwrap();
+        fs::write(root.join(MOD_TEMPLATE), "pub struct {struct};\n").unwrap();
+
+        let result = new_day(&root, 11, "CosmicExpansion").unwrap();
+
+        assert_eq!(result.len(), 5);
+        assert_eq!(
+            fs::read_to_string(root.join("src/day_11/cosmic_expansion.rs")).unwrap(),
+            "pub struct CosmicExpansion;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/day_11/mod.rs")).unwrap(),
+            "pub struct Day11;\n"
+        );
+        assert!(root.join("test_inputs/day_11.txt").exists());
+        assert!(fs::read_to_string(root.join(REGISTRY_FILE))
+            .unwrap()
+            .contains("registry.register(11, day_11::Day11);"));
+
+        assert!(new_day(&root, 11, "CosmicExpansion").is_err());
+        assert!(new_day(&root, 12, "cosmic_expansion").is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,267 @@
+use std::any::{self, Any};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("'{input}' is not a part, expected 1 or 2!")),
+        }
+    }
+}
+
+/// A solved part's answer, formatted, along with the name of the type it was formatted from.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Answer {
+    pub value: String,
+    pub type_name: &'static str,
+}
+
+impl Answer {
+    fn new<T: Display>(value: &T) -> Self {
+        let type_name = any::type_name::<T>();
+
+        Answer {
+            value: value.to_string(),
+            type_name: type_name.rsplit("::").next().unwrap_or(type_name),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&self.value)
+    }
+}
+
+/// A day's puzzle: how to parse the input and how to answer both parts from it.
+pub trait Solution {
+    type Input;
+    type Part1Answer: Display;
+    type Part2Answer: Display;
+
+    fn parse(&self, input: &[String]) -> Self::Input;
+
+    fn part_1(&self, input: &Self::Input) -> Self::Part1Answer;
+
+    fn part_2(&self, input: &Self::Input) -> Self::Part2Answer;
+}
+
+/// Type-erased [`Solution`], so days with different input and answer types can share a
+/// [`Registry`].
+///
+/// Implemented for every `Sync` [`Solution`] with a `'static` input, so days can be solved on
+/// separate threads.
+pub trait DynSolution: Sync {
+    fn parse_input(&self, input: &[String]) -> Box<dyn Any>;
+
+    /// Solves `part` from the output of [`DynSolution::parse_input`].
+    ///
+    /// # Panics
+    ///
+    /// If `input` didn't come from this solution's `parse_input`.
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse_input(&self, input: &[String]) -> Box<dyn Any> {
+        Box::new(self.parse(input))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input should come from the same solution's parse_input!");
+
+        match part {
+            Part::One => Answer::new(&self.part_1(input)),
+            Part::Two => Answer::new(&self.part_2(input)),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<u32, Box<dyn DynSolution>>,
+}
+
+impl Registry {
+    #[must_use]
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Registers the solution for `day`, replacing any previous one.
+    pub fn register<S>(&mut self, day: u32, solution: S)
+    where
+        S: DynSolution + 'static,
+    {
+        self.solutions.insert(day, Box::new(solution));
+    }
+
+    pub fn get(&self, day: u32) -> Option<&dyn DynSolution> {
+        self.solutions.get(&day).map(Box::as_ref)
+    }
+
+    /// The registered solutions, in ascending day order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &dyn DynSolution)> {
+        self.solutions
+            .iter()
+            .map(|(day, solution)| (*day, solution.as_ref()))
+    }
+
+    /// The registered days, in ascending order.
+    pub fn days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.solutions.keys().copied()
+    }
+}
+
+/// Describes a panic caught while running a solution, so it can be reported as that part's error.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown reason");
+
+    format!("Panicked: {message}")
+}
+
+/// Solutions shared by the tests of the modules that run them.
+#[cfg(test)]
+pub mod test_support {
+    use super::Solution;
+
+    /// Sums the numbers in part 1 and counts them in part 2.
+    pub struct Summer;
+
+    impl Solution for Summer {
+        type Input = Vec<u32>;
+        type Part1Answer = u32;
+        type Part2Answer = usize;
+
+        fn parse(&self, input: &[String]) -> Vec<u32> {
+            input.iter().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part_1(&self, input: &Vec<u32>) -> u32 {
+            input.iter().sum()
+        }
+
+        fn part_2(&self, input: &Vec<u32>) -> usize {
+            input.len()
+        }
+    }
+
+    /// Returns the first number in part 1 and always panics in part 2.
+    pub struct Broken;
+
+    impl Solution for Broken {
+        type Input = Vec<u32>;
+        type Part1Answer = u32;
+        type Part2Answer = u32;
+
+        fn parse(&self, input: &[String]) -> Vec<u32> {
+            input.iter().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part_1(&self, input: &Vec<u32>) -> u32 {
+            input[0]
+        }
+
+        fn part_2(&self, _input: &Vec<u32>) -> u32 {
+            panic!("Part 2 isn't done!")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use super::test_support::Summer;
+
+    #[test]
+    fn test_dyn_solution_solve() {
+        let input = ["1".to_string(), "2".to_string(), "3".to_string()];
+
+        let parsed = Summer.parse_input(&input);
+
+        let expected_part_1 = Answer {
+            value: "6".to_string(),
+            type_name: "u32",
+        };
+        let expected_part_2 = Answer {
+            value: "3".to_string(),
+            type_name: "usize",
+        };
+
+        assert_eq!(Summer.solve(parsed.as_ref(), Part::One), expected_part_1);
+        assert_eq!(Summer.solve(parsed.as_ref(), Part::Two), expected_part_2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Input should come from the same solution's parse_input!")]
+    fn test_dyn_solution_solve_wrong_input() {
+        let wrong_input: Box<dyn Any> = Box::new("not a Vec<u32>");
+
+        Summer.solve(wrong_input.as_ref(), Part::One);
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = Registry::new();
+
+        registry.register(9, Summer);
+        registry.register(2, Summer);
+
+        let expected_days = vec![2, 9];
+
+        let result_days: Vec<u32> = registry.days().collect();
+
+        assert_eq!(result_days, expected_days);
+        assert!(registry.get(2).is_some());
+        assert!(registry.get(3).is_none());
+    }
+
+    #[test]
+    fn test_part_display() {
+        assert_eq!(Part::One.to_string(), "1");
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+}
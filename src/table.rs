@@ -0,0 +1,54 @@
+/// Formats the header and rows as left aligned columns separated by ` | `, one row per line.
+#[must_use]
+pub fn format_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> String {
+    let header = header.map(str::to_string);
+
+    let mut widths = header.clone().map(|value| value.chars().count());
+
+    for row in rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.chars().count());
+        }
+    }
+
+    std::iter::once(&header)
+        .chain(rows)
+        .map(|row| {
+            row.iter()
+                .zip(widths)
+                .map(|(value, width)| format!("{value:<width$}"))
+                .collect::<Vec<String>>()
+                .join(" | ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_table() {
+        let rows = [
+            ["1".to_string(), "long value".to_string()],
+            ["100".to_string(), String::new()],
+        ];
+
+        let expected = "\
+Day | Value
+1   | long value
+100 |";
+
+        let result = format_table(["Day", "Value"], &rows);
+
+        assert_eq!(result, expected);
+        assert_eq!(format_table(["A", "B"], &[]), "A | B");
+        assert_eq!(
+            format_table(["Time", "B"], &[["1.5µs".to_string(), "x".to_string()]]),
+            "Time  | B\n1.5µs | x"
+        );
+    }
+}
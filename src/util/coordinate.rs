@@ -0,0 +1,96 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// An integer type that can be used for the coordinates of a point.
+pub trait Coordinate:
+    Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Ord
+    + Hash
+    + Debug
+    + Copy
+{
+    const ZERO: Self;
+
+    /// The absolute difference between the coordinates.
+    ///
+    /// # Panics
+    ///
+    /// If the difference doesn't fit in the type, like the distance from `i8::MIN` to `i8::MAX`.
+    #[must_use]
+    fn distance(self, other: Self) -> Self;
+
+    /// One less (`Less`), the same (`Equal`) or one more (`Greater`), if that doesn't overflow.
+    fn step(self, direction: Ordering) -> Option<Self>;
+
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+
+                fn distance(self, other: Self) -> Self {
+                    Self::try_from(self.abs_diff(other))
+                        .expect("Distance should fit in the coordinate type!")
+                }
+
+                fn step(self, direction: Ordering) -> Option<Self> {
+                    match direction {
+                        Ordering::Less => self.checked_sub(1),
+                        Ordering::Equal => Some(self),
+                        Ordering::Greater => self.checked_add(1),
+                    }
+                }
+
+                #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(3_u64.distance(10), 7);
+        assert_eq!(10_u64.distance(3), 7);
+        assert_eq!((-4_i64).distance(6), 10);
+        assert_eq!(i64::MIN.distance(-1), i64::MAX);
+        assert_eq!(u8::MAX.distance(0), u8::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "Distance should fit in the coordinate type!")]
+    fn test_distance_overflow() {
+        let _ = i8::MIN.distance(i8::MAX);
+    }
+
+    #[test]
+    fn test_step() {
+        assert_eq!(5_u32.step(Ordering::Less), Some(4));
+        assert_eq!(5_u32.step(Ordering::Equal), Some(5));
+        assert_eq!(0_u32.step(Ordering::Less), None);
+        assert_eq!(i8::MAX.step(Ordering::Greater), None);
+        assert_eq!((-1_i64).step(Ordering::Greater), Some(0));
+    }
+
+    #[test]
+    fn test_to_f64() {
+        assert!((Coordinate::to_f64(-3_i64) + 3.0).abs() < f64::EPSILON);
+        assert!((Coordinate::to_f64(u64::MAX) - 1.844_674_407_370_955_2e19).abs() < 1e4);
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The states of a sequence that eventually repeats: a prefix followed by a cycle that repeats
+/// forever.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Cycle<S> {
+    /// The prefix followed by one pass around the cycle.
+    pub states: Vec<S>,
+    pub prefix_length: usize,
+    pub cycle_length: usize,
+}
+
+impl<S> Cycle<S> {
+    #[must_use]
+    pub fn prefix(&self) -> &[S] {
+        &self.states[..self.prefix_length]
+    }
+
+    #[must_use]
+    pub fn cycle(&self) -> &[S] {
+        &self.states[self.prefix_length..]
+    }
+
+    /// The index in `states` of the state after `step` steps.
+    #[must_use]
+    pub fn index_at(&self, step: usize) -> usize {
+        step.checked_sub(self.prefix_length)
+            .map_or(step, |value| self.prefix_length + value % self.cycle_length)
+    }
+
+    /// The state after `step` steps, without simulating them.
+    #[must_use]
+    pub fn state_at(&self, step: usize) -> &S {
+        &self.states[self.index_at(step)]
+    }
+}
+
+/// Finds the cycle by remembering every state, so each state is only computed once.
+pub fn find_cycle<S, F>(start: S, mut next: F) -> Cycle<S>
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+
+    loop {
+        if let Some(&prefix_length) = seen.get(&state) {
+            return Cycle {
+                cycle_length: states.len() - prefix_length,
+                states,
+                prefix_length,
+            };
+        }
+
+        seen.insert(state.clone(), states.len());
+
+        let next_state = next(&state);
+
+        states.push(state);
+        state = next_state;
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which only needs to compare states.
+pub fn brent<S, F>(start: S, mut next: F) -> Cycle<S>
+where
+    S: Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut power = 1;
+    let mut cycle_length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = next(&start);
+
+    while tortoise != hare {
+        if power == cycle_length {
+            tortoise = hare.clone();
+            power *= 2;
+            cycle_length = 0;
+        }
+
+        hare = next(&hare);
+        cycle_length += 1;
+    }
+
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+
+    for _ in 0..cycle_length {
+        hare = next(&hare);
+    }
+
+    let mut prefix_length = 0;
+
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        prefix_length += 1;
+    }
+
+    collect_states(start, next, prefix_length, cycle_length)
+}
+
+/// Finds the cycle with Floyd's tortoise and hare algorithm, which only needs to compare states.
+pub fn floyd<S, F>(start: S, mut next: F) -> Cycle<S>
+where
+    S: Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = next(&start);
+    let mut hare = next(&tortoise);
+
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        let halfway = next(&hare);
+
+        hare = next(&halfway);
+    }
+
+    let mut tortoise = start.clone();
+    let mut prefix_length = 0;
+
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        prefix_length += 1;
+    }
+
+    let mut hare = next(&tortoise);
+    let mut cycle_length = 1;
+
+    while tortoise != hare {
+        hare = next(&hare);
+        cycle_length += 1;
+    }
+
+    collect_states(start, next, prefix_length, cycle_length)
+}
+
+/// The state after `steps` steps, skipping whole cycles once one is found.
+pub fn fast_forward<S, F>(start: S, mut next: F, steps: usize) -> S
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states: Vec<S> = Vec::new();
+    let mut state = start;
+
+    while states.len() < steps {
+        if let Some(&prefix_length) = seen.get(&state) {
+            let cycle = Cycle {
+                cycle_length: states.len() - prefix_length,
+                states,
+                prefix_length,
+            };
+
+            return cycle.state_at(steps).clone();
+        }
+
+        seen.insert(state.clone(), states.len());
+
+        let next_state = next(&state);
+
+        states.push(state);
+        state = next_state;
+    }
+
+    state
+}
+
+fn collect_states<S, F>(
+    start: S,
+    mut next: F,
+    prefix_length: usize,
+    cycle_length: usize,
+) -> Cycle<S>
+where
+    F: FnMut(&S) -> S,
+{
+    let mut states = vec![start];
+
+    while states.len() < prefix_length + cycle_length {
+        let next_state = next(states.last().unwrap());
+
+        states.push(next_state);
+    }
+
+    Cycle {
+        states,
+        prefix_length,
+        cycle_length,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Takes a reference since the cycle finders pass each state by reference.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn next(state: &u32) -> u32 {
+        if *state == 4 {
+            2
+        } else {
+            state + 1
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let expected = Cycle {
+            states: vec![0, 1, 2, 3, 4],
+            prefix_length: 2,
+            cycle_length: 3,
+        };
+
+        let result = find_cycle(0, next);
+
+        assert_eq!(result, expected);
+        assert_eq!(result.prefix(), &[0, 1]);
+        assert_eq!(result.cycle(), &[2, 3, 4]);
+    }
+
+    #[test]
+    fn test_brent_and_floyd() {
+        let expected = find_cycle(0, next);
+
+        assert_eq!(brent(0, next), expected);
+        assert_eq!(floyd(0, next), expected);
+        assert_eq!(brent(3, next), find_cycle(3, next));
+        assert_eq!(floyd(3, next), find_cycle(3, next));
+    }
+
+    #[test]
+    fn test_single_state_cycle() {
+        let expected = Cycle {
+            states: vec![7],
+            prefix_length: 0,
+            cycle_length: 1,
+        };
+
+        assert_eq!(find_cycle(7, |&state| state), expected);
+        assert_eq!(brent(7, |&state| state), expected);
+        assert_eq!(floyd(7, |&state| state), expected);
+    }
+
+    #[test]
+    fn test_state_at() {
+        let cycle = find_cycle(0, next);
+
+        let result: Vec<u32> = (0..11).map(|step| *cycle.state_at(step)).collect();
+
+        assert_eq!(result, vec![0, 1, 2, 3, 4, 2, 3, 4, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_fast_forward() {
+        assert_eq!(fast_forward(0, next, 0), 0);
+        assert_eq!(fast_forward(0, next, 3), 3);
+        assert_eq!(fast_forward(0, next, 1_000_000_000), 4);
+    }
+}
@@ -0,0 +1,283 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use super::coordinate::Coordinate;
+use super::point_2d::Point2d;
+
+/// One of the four cardinal directions on a grid where `y` points down, so `North` decreases `y`.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from `North`.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Turns 90 degrees counter-clockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    /// Turns 90 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// The point one step away in this direction, or `None` if a coordinate would overflow.
+    pub fn step<T: Coordinate>(self, point: Point2d<T>) -> Option<Point2d<T>> {
+        Heading::from(self).step(point)
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    /// Parses `NESW` or `URDL`, ignoring case, or exactly the arrows `^>v<`.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match (c, c.to_ascii_uppercase()) {
+            ('^', _) | (_, 'N' | 'U') => Ok(Direction::North),
+            ('>', _) | (_, 'E' | 'R') => Ok(Direction::East),
+            ('v', _) | (_, 'S' | 'D') => Ok(Direction::South),
+            ('<', _) | (_, 'W' | 'L') => Ok(Direction::West),
+            _ => Err(format!("Cannot convert '{c}' to a Direction!")),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(format!("Cannot convert '{s}' to a Direction!")),
+        }
+    }
+}
+
+/// One of the eight compass directions, including diagonals, on a grid where `y` points down.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Heading {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Heading {
+    /// Every heading, clockwise from `North`.
+    pub const ALL: [Heading; 8] = [
+        Heading::North,
+        Heading::NorthEast,
+        Heading::East,
+        Heading::SouthEast,
+        Heading::South,
+        Heading::SouthWest,
+        Heading::West,
+        Heading::NorthWest,
+    ];
+
+    /// Turns 45 degrees counter-clockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Heading {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Turns 45 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Heading {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Heading {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    #[must_use]
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// The point one step away in this heading, or `None` if a coordinate would overflow.
+    pub fn step<T: Coordinate>(self, point: Point2d<T>) -> Option<Point2d<T>> {
+        let (x_step, y_step) = self.offset();
+
+        Some(Point2d::new(point.x.step(x_step)?, point.y.step(y_step)?))
+    }
+
+    /// Which way the `x` and `y` coordinates change.
+    fn offset(self) -> (Ordering, Ordering) {
+        match self {
+            Heading::North => (Ordering::Equal, Ordering::Less),
+            Heading::NorthEast => (Ordering::Greater, Ordering::Less),
+            Heading::East => (Ordering::Greater, Ordering::Equal),
+            Heading::SouthEast => (Ordering::Greater, Ordering::Greater),
+            Heading::South => (Ordering::Equal, Ordering::Greater),
+            Heading::SouthWest => (Ordering::Less, Ordering::Greater),
+            Heading::West => (Ordering::Less, Ordering::Equal),
+            Heading::NorthWest => (Ordering::Less, Ordering::Less),
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl From<Direction> for Heading {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Heading::North,
+            Direction::East => Heading::East,
+            Direction::South => Heading::South,
+            Direction::West => Heading::West,
+        }
+    }
+}
+
+impl FromStr for Heading {
+    type Err = String;
+
+    /// Parses `N`, `NE`, `E`, ..., `NW`, or anything a [`Direction`] can be parsed from.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "NE" => Ok(Heading::NorthEast),
+            "SE" => Ok(Heading::SouthEast),
+            "SW" => Ok(Heading::SouthWest),
+            "NW" => Ok(Heading::NorthWest),
+            _ => s
+                .parse::<Direction>()
+                .map(Heading::from)
+                .map_err(|_| format!("Cannot convert '{s}' to a Heading!")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_turn() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().reverse(), direction);
+        }
+
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn test_direction_step() {
+        let point: Point2d<usize> = Point2d::new(0, 1);
+
+        assert_eq!(Direction::North.step(point), Some(Point2d::new(0, 0)));
+        assert_eq!(Direction::East.step(point), Some(Point2d::new(1, 1)));
+        assert_eq!(Direction::South.step(point), Some(Point2d::new(0, 2)));
+        assert_eq!(Direction::West.step(point), None);
+    }
+
+    #[test]
+    fn test_direction_parse() {
+        let expected = vec![
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ];
+
+        for input in ["NESW", "URDL", "^>v<", "nesw"] {
+            let result: Vec<Direction> = input
+                .chars()
+                .map(|c| Direction::try_from(c).unwrap())
+                .collect();
+
+            assert_eq!(result, expected);
+        }
+
+        assert_eq!("D".parse(), Ok(Direction::South));
+        assert!("X".parse::<Direction>().is_err());
+        assert!("V".parse::<Direction>().is_err());
+        assert!("NE".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn test_heading_turn() {
+        assert_eq!(Heading::North.turn_right(), Heading::NorthEast);
+        assert_eq!(Heading::North.turn_left(), Heading::NorthWest);
+        assert_eq!(Heading::SouthEast.reverse(), Heading::NorthWest);
+        assert!(Heading::SouthWest.is_diagonal());
+        assert!(!Heading::West.is_diagonal());
+    }
+
+    #[test]
+    fn test_heading_step() {
+        let point = Point2d::new(0, 0);
+
+        let expected = vec![
+            Point2d::new(0, -1),
+            Point2d::new(1, -1),
+            Point2d::new(1, 0),
+            Point2d::new(1, 1),
+            Point2d::new(0, 1),
+            Point2d::new(-1, 1),
+            Point2d::new(-1, 0),
+            Point2d::new(-1, -1),
+        ];
+
+        let result: Vec<Point2d<i32>> = Heading::ALL
+            .iter()
+            .filter_map(|heading| heading.step(point))
+            .collect();
+
+        assert_eq!(result, expected);
+        assert_eq!(Heading::NorthEast.step(Point2d::new(0_u8, 0)), None);
+    }
+
+    #[test]
+    fn test_heading_parse() {
+        assert_eq!("ne".parse(), Ok(Heading::NorthEast));
+        assert_eq!("<".parse(), Ok(Heading::West));
+        assert!("NNE".parse::<Heading>().is_err());
+    }
+}
@@ -1,22 +1,246 @@
+use std::fmt;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader, ErrorKind};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ReadError {
+    NotFound { path: String },
+    PermissionDenied { path: String },
+    InvalidUtf8 { path: String, line: usize },
+    Io { path: String, message: String },
+}
+
+impl ReadError {
+    fn new(path: &str, line: Option<usize>, error: &io::Error) -> Self {
+        let path = path.to_string();
+
+        match (error.kind(), line) {
+            (ErrorKind::NotFound, _) => ReadError::NotFound { path },
+            (ErrorKind::PermissionDenied, _) => ReadError::PermissionDenied { path },
+            (ErrorKind::InvalidData, Some(line)) => ReadError::InvalidUtf8 { path, line },
+            _ => ReadError::Io {
+                path,
+                message: error.to_string(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadError::NotFound { path } => write!(f, "File not found: {path}"),
+            ReadError::PermissionDenied { path } => write!(f, "Permission denied: {path}"),
+            ReadError::InvalidUtf8 { path, line } => {
+                write!(f, "{path} line {line} is not valid UTF-8")
+            }
+            ReadError::Io { path, message } => write!(f, "Couldn't read {path}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+/// Where a puzzle input is read from.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InputSource {
+    Path(String),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    /// Reads the input and returns it's contents as a vector of strings.
+    ///
+    /// # Errors
+    ///
+    /// If the file doesn't exist or can't be opened, or a line couldn't be read.
+    pub fn read_lines(&self) -> Result<Vec<String>, ReadError> {
+        match self {
+            InputSource::Path(path) => to_string_vector(path),
+            InputSource::Stdin => lines_from(io::stdin().lock(), &self.to_string()),
+            InputSource::Inline(text) => Ok(text.lines().map(str::to_string).collect()),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "{path}"),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Inline(_) => write!(f, "<inline>"),
+        }
+    }
+}
 
 /// Takes a file and returns it's contents as a vector of strings.
 ///
 /// # Errors
 ///
-/// N/A
+/// If the file doesn't exist or can't be opened, or a line couldn't be read.
+pub fn to_string_vector(file_name: &str) -> Result<Vec<String>, ReadError> {
+    let file = File::open(file_name).map_err(|error| ReadError::new(file_name, None, &error))?;
+
+    lines_from(BufReader::new(file), file_name)
+}
+
+/// A problem with a specific (1-based) line of the input.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LineError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for LineError {}
+
+/// A run of consecutive non-blank lines.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Section<'a> {
+    /// The (1-based) line number of the section's first line in the whole input.
+    pub first_line: usize,
+    pub lines: &'a [String],
+}
+
+impl Section<'_> {
+    /// See [`to_number_rows`], with line numbers relative to the whole input.
+    ///
+    /// # Errors
+    ///
+    /// If a value can't be parsed as `T`.
+    pub fn number_rows<T: FromStr>(&self) -> Result<Vec<Vec<T>>, LineError> {
+        number_rows_from(self.lines, self.first_line)
+    }
+
+    /// See [`to_char_grid`], with line numbers relative to the whole input.
+    ///
+    /// # Errors
+    ///
+    /// If the rows aren't all the same length.
+    pub fn char_grid(&self) -> Result<Vec<Vec<char>>, LineError> {
+        char_grid_from(self.lines, self.first_line)
+    }
+}
+
+/// Splits the lines into sections separated by one or more blank lines.
+#[must_use]
+pub fn to_sections(lines: &[String]) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+
+    for (index, line) in lines.iter().enumerate() {
+        if is_blank(line) {
+            if start < index {
+                sections.push(Section {
+                    first_line: start + 1,
+                    lines: &lines[start..index],
+                });
+            }
+
+            start = index + 1;
+        }
+    }
+
+    if start < lines.len() {
+        sections.push(Section {
+            first_line: start + 1,
+            lines: &lines[start..],
+        });
+    }
+
+    sections
+}
+
+/// Parses each line into its whitespace-separated values.
+///
+/// A leading `label:` without digits (e.g. `Time:` or `seeds:`) is skipped.
+///
+/// # Errors
+///
+/// If a value can't be parsed as `T`.
+pub fn to_number_rows<T: FromStr>(lines: &[String]) -> Result<Vec<Vec<T>>, LineError> {
+    number_rows_from(lines, 1)
+}
+
+/// Collects each line's characters into a row of a rectangular grid.
+///
+/// Trailing blank lines are ignored.
 ///
-/// # Panics
+/// # Errors
 ///
-/// If line couldn't be read and/or file doesn't exist.
-pub fn to_string_vector(file_name: &str) -> Result<Vec<String>, String> {
-    let file = BufReader::new(File::open(file_name).expect("File not found!"));
+/// If the rows aren't all the same length.
+pub fn to_char_grid(lines: &[String]) -> Result<Vec<Vec<char>>, LineError> {
+    char_grid_from(lines, 1)
+}
 
-    Ok(file
+fn number_rows_from<T: FromStr>(
+    lines: &[String],
+    first_line: usize,
+) -> Result<Vec<Vec<T>>, LineError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let values = match line.split_once(':') {
+                Some((label, rest)) if !label.contains(|c: char| c.is_ascii_digit()) => rest,
+                _ => line.as_str(),
+            };
+
+            values
+                .split_whitespace()
+                .map(|value| {
+                    value.parse().map_err(|_| LineError {
+                        line: first_line + index,
+                        message: format!("'{value}' is not a valid number!"),
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Whether the line separates sections or trails the input, which whitespace alone still does.
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+fn char_grid_from(lines: &[String], first_line: usize) -> Result<Vec<Vec<char>>, LineError> {
+    let height = lines
+        .iter()
+        .rposition(|line| !is_blank(line))
+        .map_or(0, |index| index + 1);
+
+    let grid: Vec<Vec<char>> = lines[..height]
+        .iter()
+        .map(|line| line.chars().collect())
+        .collect();
+
+    let width = grid.first().map_or(0, Vec::len);
+
+    match grid.iter().position(|row| row.len() != width) {
+        Some(index) => Err(LineError {
+            line: first_line + index,
+            message: format!("expected {width} characters, found {}!", grid[index].len()),
+        }),
+        None => Ok(grid),
+    }
+}
+
+fn lines_from<R: BufRead>(reader: R, name: &str) -> Result<Vec<String>, ReadError> {
+    reader
         .lines()
-        .map(|line| line.expect("The file is bad!"))
-        .collect())
+        .enumerate()
+        .map(|(line_index, line)| {
+            line.map_err(|error| ReadError::new(name, Some(line_index + 1), &error))
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -46,4 +270,178 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_to_string_vector_not_found() {
+        let expected = ReadError::NotFound {
+            path: "test_inputs/does_not_exist.txt".to_string(),
+        };
+
+        let result = to_string_vector("test_inputs/does_not_exist.txt");
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_to_string_vector_invalid_utf8() {
+        let expected = ReadError::InvalidUtf8 {
+            path: "test_inputs/invalid_utf8.txt".to_string(),
+            line: 2,
+        };
+
+        let result = to_string_vector("test_inputs/invalid_utf8.txt");
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_read_error_display() {
+        let not_found = ReadError::NotFound {
+            path: "inputs/day_26.txt".to_string(),
+        };
+        let invalid_utf8 = ReadError::InvalidUtf8 {
+            path: "inputs/day_1.txt".to_string(),
+            line: 7,
+        };
+
+        assert_eq!(not_found.to_string(), "File not found: inputs/day_26.txt");
+        assert_eq!(
+            invalid_utf8.to_string(),
+            "inputs/day_1.txt line 7 is not valid UTF-8"
+        );
+    }
+
+    #[test]
+    fn test_input_source_read_lines() {
+        let path = InputSource::Path("test_inputs/day_6.txt".to_string());
+        let inline =
+            InputSource::Inline("Time:      7  15   30\nDistance:  9  40  200\n".to_string());
+
+        let expected = vec![
+            "Time:      7  15   30".to_string(),
+            "Distance:  9  40  200".to_string(),
+        ];
+
+        assert_eq!(path.read_lines().unwrap(), expected);
+        assert_eq!(inline.read_lines().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_input_source_read_lines_err() {
+        let path = InputSource::Path("test_inputs/does_not_exist.txt".to_string());
+
+        assert!(path.read_lines().is_err());
+    }
+
+    #[test]
+    fn test_lines_from_invalid_utf8() {
+        let reader: &[u8] = b"fine\n\xff\n";
+
+        let expected = ReadError::InvalidUtf8 {
+            path: "<stdin>".to_string(),
+            line: 2,
+        };
+
+        let result = lines_from(reader, &InputSource::Stdin.to_string());
+
+        assert_eq!(result, Err(expected));
+    }
+
+    fn to_lines(input: &str) -> Vec<String> {
+        input.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_to_sections() {
+        let lines = to_lines("a\nb\n\n\nc\n\nd\ne\n");
+
+        let expected = vec![
+            Section {
+                first_line: 1,
+                lines: &lines[0..2],
+            },
+            Section {
+                first_line: 5,
+                lines: &lines[4..5],
+            },
+            Section {
+                first_line: 7,
+                lines: &lines[6..8],
+            },
+        ];
+
+        let result = to_sections(&lines);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_to_number_rows() {
+        let lines = to_lines("Time:      7  15   30\n-1 2 -3");
+
+        let expected = vec![vec![7, 15, 30], vec![-1, 2, -3]];
+
+        let result = to_number_rows::<i64>(&lines).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_section_number_rows_err() {
+        let lines = to_lines("1 2\n\n3 4\n5 x");
+
+        let expected = LineError {
+            line: 4,
+            message: "'x' is not a valid number!".to_string(),
+        };
+
+        let result = to_sections(&lines)[1].number_rows::<u32>();
+
+        assert_eq!(result, Err(expected));
+        assert!(to_number_rows::<u32>(&to_lines("1 -2")).is_err());
+    }
+
+    #[test]
+    fn test_to_number_rows_colon_after_number_err() {
+        let lines = to_lines("1:2 3");
+
+        let expected = LineError {
+            line: 1,
+            message: "'1:2' is not a valid number!".to_string(),
+        };
+
+        let result = to_number_rows::<u32>(&lines);
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_to_char_grid() {
+        let lines = to_lines("ab\ncd\n\n");
+
+        let expected = vec![vec!['a', 'b'], vec!['c', 'd']];
+
+        let result = to_char_grid(&lines).unwrap();
+
+        assert_eq!(result, expected);
+        assert_eq!(to_char_grid(&to_lines("ab\ncd\n  \n\t")), Ok(expected));
+    }
+
+    #[test]
+    fn test_section_char_grid_err() {
+        let lines = to_lines("..\n\n...\n..\n...");
+
+        let expected = LineError {
+            line: 4,
+            message: "expected 3 characters, found 2!".to_string(),
+        };
+
+        let result = to_sections(&lines)[1].char_grid();
+
+        assert_eq!(result, Err(expected.clone()));
+        assert_eq!(
+            expected.to_string(),
+            "Line 4: expected 3 characters, found 2!"
+        );
+    }
 }
@@ -0,0 +1,381 @@
+use std::fmt;
+
+use super::file_reader::{to_char_grid, LineError};
+use super::point_2d::Point2d;
+
+const NEIGHBORS_4: [(i32, i32); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const NEIGHBORS_8: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid, indexed by points where `x` is the column and `y` is the row, with the
+/// origin in the top left.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Creates a grid of the characters of each line.
+    ///
+    /// Trailing blank lines are ignored.
+    ///
+    /// # Errors
+    ///
+    /// If the lines aren't all the same length.
+    pub fn from_lines(lines: &[String]) -> Result<Self, LineError> {
+        to_char_grid(lines).map(Self::from_rectangular_rows)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its rows.
+    ///
+    /// # Errors
+    ///
+    /// If the rows aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let width = rows.first().map_or(0, Vec::len);
+
+        match rows.iter().position(|row| row.len() != width) {
+            Some(index) => Err(format!(
+                "Row {index} has {} values, expected {width}!",
+                rows[index].len()
+            )),
+            None => Ok(Self::from_rectangular_rows(rows)),
+        }
+    }
+
+    fn from_rectangular_rows(rows: Vec<Vec<T>>) -> Self {
+        Grid {
+            width: rows.first().map_or(0, Vec::len),
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    fn from_fn<F>(width: usize, height: usize, mut value_at: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| value_at(x, y))
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Point2d<i32>) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    #[must_use]
+    pub fn get(&self, point: Point2d<i32>) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point2d<i32>) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point2d<i32>> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |index| Self::point_of(index, width))
+    }
+
+    /// Every value in the grid with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2d<i32>, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    #[must_use]
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(if x < self.width { self.height } else { 0 })
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Vec<&T>> {
+        (0..self.width).map(|x| self.column(x).collect())
+    }
+
+    /// The points above, left, right and below `point` that are in the grid.
+    pub fn neighbors_4(&self, point: Point2d<i32>) -> impl Iterator<Item = Point2d<i32>> + '_ {
+        self.neighbors(point, &NEIGHBORS_4)
+    }
+
+    /// The points surrounding `point`, including diagonals, that are in the grid.
+    pub fn neighbors_8(&self, point: Point2d<i32>) -> impl Iterator<Item = Point2d<i32>> + '_ {
+        self.neighbors(point, &NEIGHBORS_8)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        point: Point2d<i32>,
+        offsets: &'a [(i32, i32)],
+    ) -> impl Iterator<Item = Point2d<i32>> + 'a {
+        offsets
+            .iter()
+            .map(move |&offset| point.add_t(offset))
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    pub fn map<U, F>(&self, mut transform: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        self.map_with_points(|_, value| transform(value))
+    }
+
+    pub fn map_with_points<U, F>(&self, mut transform: F) -> Grid<U>
+    where
+        F: FnMut(Point2d<i32>, &T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self
+                .iter()
+                .map(|(point, value)| transform(point, value))
+                .collect(),
+        }
+    }
+
+    /// Changes every value in place.
+    pub fn transform<F>(&mut self, mut transform: F)
+    where
+        F: FnMut(Point2d<i32>, &mut T),
+    {
+        let width = self.width;
+
+        for (index, value) in self.cells.iter_mut().enumerate() {
+            transform(Self::point_of(index, width), value);
+        }
+    }
+
+    fn index_of(&self, point: Point2d<i32>) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+
+        Some(y * self.width + x)
+    }
+
+    fn point_of(index: usize, width: usize) -> Point2d<i32> {
+        Point2d::new(
+            i32::try_from(index % width).expect("Grid should fit in i32!"),
+            i32::try_from(index / width).expect("Grid should fit in i32!"),
+        )
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps the rows and columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self.cells[x * self.width + y].clone()
+        })
+    }
+
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self.cells[(self.height - 1 - x) * self.width + y].clone()
+        })
+    }
+
+    #[must_use]
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self.cells[x * self.width + (self.width - 1 - y)].clone()
+        })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+
+            for value in row {
+                write!(f, "{value}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_grid(input: &str) -> Grid<char> {
+        let lines: Vec<String> = input.lines().map(str::to_string).collect();
+
+        Grid::from_lines(&lines).unwrap()
+    }
+
+    #[test]
+    fn test_from_lines() {
+        let expected = Grid {
+            width: 3,
+            height: 2,
+            cells: vec!['a', 'b', 'c', 'd', 'e', 'f'],
+        };
+
+        let result = to_grid("abc\ndef\n");
+
+        assert_eq!(result, expected);
+        assert!(Grid::from_lines(&["ab".to_string(), "c".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_from_rows_err() {
+        let result = Grid::from_rows(vec![vec![1, 2], vec![3]]);
+
+        assert_eq!(result, Err("Row 1 has 1 values, expected 2!".to_string()));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = to_grid("abc\ndef");
+
+        assert_eq!(grid.get(Point2d::new(2, 0)), Some(&'c'));
+        assert_eq!(grid.get(Point2d::new(0, 1)), Some(&'d'));
+        assert_eq!(grid.get(Point2d::new(3, 0)), None);
+        assert_eq!(grid.get(Point2d::new(0, 2)), None);
+        assert_eq!(grid.get(Point2d::new(-1, 0)), None);
+
+        *grid.get_mut(Point2d::new(1, 1)).unwrap() = 'E';
+
+        assert_eq!(grid.to_string(), "abc\ndEf");
+    }
+
+    #[test]
+    fn test_iter() {
+        let grid = to_grid("ab\ncd");
+
+        let expected = vec![
+            (Point2d::new(0, 0), &'a'),
+            (Point2d::new(1, 0), &'b'),
+            (Point2d::new(0, 1), &'c'),
+            (Point2d::new(1, 1), &'d'),
+        ];
+
+        let result: Vec<(Point2d<i32>, &char)> = grid.iter().collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = to_grid("abc\ndef");
+
+        let rows: Vec<&[char]> = grid.rows().collect();
+        let columns: Vec<Vec<&char>> = grid.columns().collect();
+
+        assert_eq!(rows, vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]]);
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(
+            columns,
+            vec![vec![&'a', &'d'], vec![&'b', &'e'], vec![&'c', &'f']]
+        );
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = to_grid("abc\ndef\nghi");
+
+        let corner_4: Vec<Point2d<i32>> = grid.neighbors_4(Point2d::new(0, 0)).collect();
+        let center_4: Vec<Point2d<i32>> = grid.neighbors_4(Point2d::new(1, 1)).collect();
+        let corner_8: Vec<Point2d<i32>> = grid.neighbors_8(Point2d::new(2, 2)).collect();
+
+        assert_eq!(corner_4, vec![Point2d::new(1, 0), Point2d::new(0, 1)]);
+        assert_eq!(
+            center_4,
+            vec![
+                Point2d::new(1, 0),
+                Point2d::new(0, 1),
+                Point2d::new(2, 1),
+                Point2d::new(1, 2)
+            ]
+        );
+        assert_eq!(
+            corner_8,
+            vec![Point2d::new(1, 1), Point2d::new(2, 1), Point2d::new(1, 2)]
+        );
+        assert_eq!(grid.neighbors_8(Point2d::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_map_and_transform() {
+        let mut grid = to_grid("12\n34");
+
+        let digits = grid.map(|c| c.to_digit(10).unwrap());
+        let sums = digits
+            .map_with_points(|point, digit| point.x + point.y + i32::try_from(*digit).unwrap());
+
+        grid.transform(|point, c| {
+            if point.x == point.y {
+                *c = '.';
+            }
+        });
+
+        assert_eq!(digits.to_string(), "12\n34");
+        assert_eq!(sums.to_string(), "13\n46");
+        assert_eq!(grid.to_string(), ".2\n3.");
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = to_grid("abc\ndef");
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+}
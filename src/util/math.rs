@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+use std::fmt;
 use std::mem;
 use std::ops::{Div, Mul, Rem};
 
@@ -33,8 +35,24 @@ where
     first * (second / gcd(first, second))
 }
 
+/// The gcd of the coefficients, along with an `x` and `y` where `left_coeff * x + right_coeff * y`
+/// is that gcd.
+///
+/// # Panics
+///
+/// If the gcd or either factor doesn't fit in an `i64`.
 #[must_use]
 pub fn extended_euclidean(left_coeff: i64, right_coeff: i64) -> (i64, i64, i64) {
+    let (gcd, left_factor, right_factor) =
+        extended_euclidean_i128(i128::from(left_coeff), i128::from(right_coeff));
+
+    let narrow =
+        |value: i128| i64::try_from(value).expect("Bezout coefficients should fit in i64!");
+
+    (narrow(gcd), narrow(left_factor), narrow(right_factor))
+}
+
+fn extended_euclidean_i128(left_coeff: i128, right_coeff: i128) -> (i128, i128, i128) {
     let (mut old_rem, mut rem) = (left_coeff, right_coeff);
     let (mut old_s, mut s_coeff) = (1, 0);
     let (mut old_t, mut t_coeff) = (0, 1);
@@ -50,44 +68,189 @@ pub fn extended_euclidean(left_coeff: i64, right_coeff: i64) -> (i64, i64, i64)
     (old_rem, old_s, old_t)
 }
 
+/// `base` to the power of `exponent`, modulo `modulus`, without overflowing.
+///
+/// # Panics
+///
+/// If `modulus` is zero.
+#[must_use]
+pub fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let modulus = u128::from(modulus);
+
+    let mut result = 1 % modulus;
+    let mut base = u128::from(base) % modulus;
+    let mut exponent = exponent;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+
+    u64::try_from(result).expect("Result should be less than the modulus!")
+}
+
+/// The `x` in `0..modulus` where `value * x` is 1 modulo `modulus`, or `None` if `value` and
+/// `modulus` aren't coprime or `modulus` isn't positive.
+#[must_use]
+pub fn mod_inverse(value: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+
+    let (gcd, inverse, _) = extended_euclidean(value.rem_euclid(modulus), modulus);
+
+    (gcd == 1).then(|| inverse.rem_euclid(modulus))
+}
+
+/// Solves the system `x = remainder (mod modulus)` for every `(remainder, modulus)`, where the
+/// moduli don't need to be coprime.
+///
+/// Returns the smallest non-negative solution and the modulus all solutions repeat with, or
+/// `None` if the congruences contradict each other or a modulus isn't positive.
 #[must_use]
-#[allow(
-    clippy::cast_possible_truncation,
-    clippy::cast_precision_loss,
-    clippy::similar_names
-)]
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences.iter().try_fold(
+        (0, 1),
+        |(remainder, modulus), &(other_remainder, other_modulus)| {
+            if other_modulus <= 0 {
+                return None;
+            }
+
+            // Both remainders are now within their moduli, so subtracting them can't overflow.
+            let other_remainder = other_remainder.rem_euclid(other_modulus);
+
+            let (gcd, inverse, _) = extended_euclidean(modulus, other_modulus);
+            let diff = other_remainder - remainder;
+
+            if diff % gcd != 0 {
+                return None;
+            }
+
+            let reduced_modulus = i128::from(other_modulus / gcd);
+            let combined_modulus = i128::from(modulus) * reduced_modulus;
+
+            let multiple =
+                (i128::from(diff / gcd) * i128::from(inverse)).rem_euclid(reduced_modulus);
+            let combined = (i128::from(remainder) + i128::from(modulus) * multiple)
+                .rem_euclid(combined_modulus);
+
+            Some((
+                i64::try_from(combined).ok()?,
+                i64::try_from(combined_modulus).ok()?,
+            ))
+        },
+    )
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DiophantineError {
+    /// There are no integer solutions at all.
+    NoSolution,
+    /// There are integer solutions, but none where both values are non-negative and not both zero.
+    NoPositiveSolution,
+    /// The smallest positive solution doesn't fit in an `i64`.
+    TooLarge,
+}
+
+impl fmt::Display for DiophantineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiophantineError::NoSolution => write!(f, "The equation has no integer solutions"),
+            DiophantineError::NoPositiveSolution => {
+                write!(f, "The equation has no non-negative solutions")
+            }
+            DiophantineError::TooLarge => write!(f, "The smallest solution doesn't fit in an i64"),
+        }
+    }
+}
+
+impl std::error::Error for DiophantineError {}
+
+/// The solution to `left_coeff * x + right_coeff * y = diff` with the smallest `x`, then the
+/// smallest `y`, where both are non-negative and not both zero.
+///
+/// # Errors
+///
+/// If there are no integer solutions, none of them are positive, or the smallest one doesn't fit
+/// in an `i64`.
 pub fn min_positive_linear_diophantine(
     left_coeff: i64,
     right_coeff: i64,
-    diff_i: i64,
-) -> Option<(i64, i64)> {
-    let (gcd_i, xg_i, yg_i) = extended_euclidean(left_coeff.abs(), right_coeff.abs());
+    diff: i64,
+) -> Result<(i64, i64), DiophantineError> {
+    let (left, right, diff) = (
+        i128::from(left_coeff),
+        i128::from(right_coeff),
+        i128::from(diff),
+    );
 
-    let (gcd, xg, yg) = ((gcd_i as f64), (xg_i as f64), (yg_i as f64));
-    let diff = diff_i as f64;
+    if left == 0 && right == 0 {
+        return if diff == 0 {
+            Ok((0, 1))
+        } else {
+            Err(DiophantineError::NoSolution)
+        };
+    }
 
-    let (x0, y0) = (
-        xg * (diff / gcd) * (left_coeff.signum() as f64),
-        yg * (diff / gcd) * (right_coeff.signum() as f64),
-    );
+    let (gcd, left_factor, right_factor) = extended_euclidean_i128(left.abs(), right.abs());
 
-    let max_k_for_positive_x: i64 = if right_coeff < 0 {
-        ((x0 * -gcd) / (right_coeff as f64)).floor() as i64
-    } else {
-        i64::MAX
-    };
+    if diff % gcd != 0 {
+        return Err(DiophantineError::NoSolution);
+    }
+
+    // Every solution is `(x_start + x_step * k, y_start + y_step * k)` for some integer `k`.
+    let x_start = left_factor * left.signum() * (diff / gcd);
+    let y_start = right_factor * right.signum() * (diff / gcd);
+    let (x_step, y_step) = (right / gcd, -left / gcd);
+
+    let solution_at = |k: i128| (x_start + x_step * k, y_start + y_step * k);
+
+    // The range of `k` where both values are non-negative.
+    let mut min_k: Option<i128> = None;
+    let mut max_k: Option<i128> = None;
 
-    let max_k_for_positive_y: i64 = ((y0 * gcd) / (left_coeff as f64)).floor() as i64;
+    for (start, step) in [(x_start, x_step), (y_start, y_step)] {
+        match step.cmp(&0) {
+            Ordering::Greater => {
+                let bound = -start.div_euclid(step);
 
-    let max_k = max_k_for_positive_y.min(max_k_for_positive_x);
+                min_k = Some(min_k.map_or(bound, |k| k.max(bound)));
+            }
+            Ordering::Less => {
+                let bound = start.div_euclid(-step);
 
-    let min_x = (x0 as i64) + (right_coeff / gcd_i) * max_k;
-    let max_y = (y0 as i64) - (left_coeff / gcd_i) * max_k;
+                max_k = Some(max_k.map_or(bound, |k| k.min(bound)));
+            }
+            Ordering::Equal if start < 0 => return Err(DiophantineError::NoPositiveSolution),
+            Ordering::Equal => {}
+        }
+    }
+
+    // Moving `k` this way makes `x` bigger, or `y` if `x` can't change.
+    let increasing = if x_step == 0 { y_step > 0 } else { x_step > 0 };
+
+    let best_k =
+        if increasing { min_k } else { max_k }.ok_or(DiophantineError::NoPositiveSolution)?;
+
+    let k = match (solution_at(best_k), increasing) {
+        ((0, 0), true) => best_k + 1,
+        ((0, 0), false) => best_k - 1,
+        _ => best_k,
+    };
+
+    if min_k.is_some_and(|min| k < min) || max_k.is_some_and(|max| k > max) {
+        return Err(DiophantineError::NoPositiveSolution);
+    }
+
+    let (x, y) = solution_at(k);
 
-    match (min_x, max_y) {
-        (0, 0) => None,
-        (x, y) if (x >= 0) && (y >= 0) => Some((x, y)),
-        _ => None,
+    match (i64::try_from(x), i64::try_from(y)) {
+        (Ok(x), Ok(y)) => Ok((x, y)),
+        _ => Err(DiophantineError::TooLarge),
     }
 }
 
@@ -145,8 +308,134 @@ mod tests {
 
     #[test]
     fn test_min_positive_linear_diophantine() {
-        assert_eq!(min_positive_linear_diophantine(3, -50, 44), Some((48, 2)));
-        assert_eq!(min_positive_linear_diophantine(3, 50, 44), None);
-        assert_eq!(min_positive_linear_diophantine(50, 3, 44), None);
+        assert_eq!(min_positive_linear_diophantine(3, -50, 44), Ok((48, 2)));
+        assert_eq!(
+            min_positive_linear_diophantine(3, 50, 44),
+            Err(DiophantineError::NoPositiveSolution)
+        );
+        assert_eq!(
+            min_positive_linear_diophantine(50, 3, 44),
+            Err(DiophantineError::NoPositiveSolution)
+        );
+        assert_eq!(
+            min_positive_linear_diophantine(4, -6, 3),
+            Err(DiophantineError::NoSolution)
+        );
+        assert_eq!(min_positive_linear_diophantine(3, -5, 0), Ok((5, 3)));
+        assert_eq!(min_positive_linear_diophantine(0, 7, 21), Ok((0, 3)));
+    }
+
+    #[test]
+    fn test_min_positive_linear_diophantine_large() {
+        let left = (1 << 53) + 1;
+
+        let expected = Ok((1, 1 << 52));
+
+        let result = min_positive_linear_diophantine(left, -2, 1);
+
+        assert_eq!(result, expected);
+        assert_eq!(
+            min_positive_linear_diophantine(i64::MAX, i64::MIN, 1),
+            Ok((i64::MAX, i64::MAX - 1))
+        );
+    }
+
+    #[test]
+    fn test_min_positive_linear_diophantine_brute_force() {
+        // Big enough to contain the smallest solution for every equation checked.
+        const LIMIT: i64 = 50;
+
+        for left in -9_i64..=9 {
+            for right in -9_i64..=9 {
+                for diff in -20..=20 {
+                    let divisor = extended_euclidean(left.abs(), right.abs()).0;
+                    let solvable = if divisor == 0 {
+                        diff == 0
+                    } else {
+                        diff % divisor == 0
+                    };
+
+                    let expected = (0..=LIMIT)
+                        .flat_map(|x| (0..=LIMIT).map(move |y| (x, y)))
+                        .find(|&(x, y)| (x, y) != (0, 0) && left * x + right * y == diff)
+                        .ok_or(if solvable {
+                            DiophantineError::NoPositiveSolution
+                        } else {
+                            DiophantineError::NoSolution
+                        });
+
+                    let result = min_positive_linear_diophantine(left, right, diff);
+
+                    assert_eq!(result, expected, "{left} * x + {right} * y = {diff}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_min_positive_linear_diophantine_large_random() {
+        let mut seed: u64 = 0x2023_1208;
+        let mut random = || {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+
+            i64::try_from(seed >> 20).unwrap() - (1 << 43)
+        };
+
+        for _ in 0..1_000 {
+            let (left, right, diff) = (random(), random(), random());
+            let divisor = extended_euclidean(left.abs(), right.abs()).0;
+
+            match min_positive_linear_diophantine(left, right, diff) {
+                Ok((x, y)) => {
+                    assert!(x >= 0 && y >= 0 && (x, y) != (0, 0));
+                    assert_eq!(
+                        i128::from(left) * i128::from(x) + i128::from(right) * i128::from(y),
+                        i128::from(diff)
+                    );
+                }
+                Err(DiophantineError::NoSolution) => assert_ne!(diff % divisor, 0),
+                Err(_) => assert_eq!(diff % divisor, 0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(2, 0, 7), 1);
+        assert_eq!(mod_pow(5, 3, 1), 0);
+        assert_eq!(mod_pow(u64::MAX - 1, u64::MAX, u64::MAX), u64::MAX - 1);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(3, 0), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(6, 3), (50, 50)]), Some((0, 150)));
+        assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[(3, 4), (4, 6)]), None);
+        assert_eq!(crt(&[(-1, 4)]), Some((3, 4)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 0)]), None);
+    }
+
+    #[test]
+    fn test_crt_extreme_remainders() {
+        assert_eq!(crt(&[(1, 2), (i64::MIN, 3)]), Some((1, 6)));
+        assert_eq!(crt(&[(i64::MAX, 2), (i64::MIN, 3)]), Some((1, 6)));
+        assert_eq!(
+            crt(&[(i64::MAX, i64::MAX), (i64::MIN, 1)]),
+            Some((0, i64::MAX))
+        );
     }
 }
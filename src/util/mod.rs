@@ -1,4 +1,11 @@
+pub mod coordinate;
+pub mod cycle;
+pub mod direction;
 pub mod file_reader;
+pub mod grid;
 pub mod location;
 pub mod math;
 pub mod point_2d;
+pub mod point_3d;
+pub mod point_n;
+pub mod search;
@@ -1,17 +1,12 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops;
 
+use super::coordinate::Coordinate;
 use super::location::Location;
 
 #[derive(Debug, Eq, PartialEq, PartialOrd, Hash, Copy, Clone, Ord)]
 pub struct Point2d<T>
 where
-    T: Add<Output = T>
-        + Sub<Output = T>
-        + Mul<Output = T>
-        + Div<Output = T>
-        + Ord
-        + Into<f64>
-        + Copy,
+    T: Coordinate,
 {
     pub x: T,
     pub y: T,
@@ -19,13 +14,7 @@ where
 
 impl<T> From<(T, T)> for Point2d<T>
 where
-    T: Add<Output = T>
-        + Sub<Output = T>
-        + Mul<Output = T>
-        + Div<Output = T>
-        + Ord
-        + Into<f64>
-        + Copy,
+    T: Coordinate,
 {
     fn from((x, y): (T, T)) -> Point2d<T> {
         Point2d { x, y }
@@ -34,13 +23,7 @@ where
 
 impl<T> Point2d<T>
 where
-    T: Add<Output = T>
-        + Sub<Output = T>
-        + Mul<Output = T>
-        + Div<Output = T>
-        + Ord
-        + Into<f64>
-        + Copy,
+    T: Coordinate,
 {
     pub fn new(x: T, y: T) -> Point2d<T> {
         Point2d { x, y }
@@ -53,41 +36,100 @@ where
             y: self.y + other.1,
         }
     }
+
+    /// The largest distance along either axis, i.e. the number of king moves between the points.
+    pub fn chebyshev_distance_to(&self, other: &Point2d<T>) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+}
+
+impl<T> Point2d<T>
+where
+    T: Coordinate + ops::Neg<Output = T>,
+{
+    /// Rotates the point 90 degrees counter-clockwise around the origin, with `y` pointing up.
+    ///
+    /// On a grid where `y` points down this turns clockwise instead.
+    #[must_use]
+    pub fn rotate_left(&self) -> Point2d<T> {
+        Point2d::new(-self.y, self.x)
+    }
+
+    /// Rotates the point 90 degrees clockwise around the origin, with `y` pointing up.
+    ///
+    /// On a grid where `y` points down this turns counter-clockwise instead.
+    #[must_use]
+    pub fn rotate_right(&self) -> Point2d<T> {
+        Point2d::new(self.y, -self.x)
+    }
+}
+
+impl<T> ops::Add for Point2d<T>
+where
+    T: Coordinate,
+{
+    type Output = Point2d<T>;
+
+    fn add(self, other: Point2d<T>) -> Point2d<T> {
+        Point2d::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T> ops::Sub for Point2d<T>
+where
+    T: Coordinate,
+{
+    type Output = Point2d<T>;
+
+    fn sub(self, other: Point2d<T>) -> Point2d<T> {
+        Point2d::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T> ops::Neg for Point2d<T>
+where
+    T: Coordinate + ops::Neg<Output = T>,
+{
+    type Output = Point2d<T>;
+
+    fn neg(self) -> Point2d<T> {
+        Point2d::new(-self.x, -self.y)
+    }
+}
+
+impl<T> ops::Mul<T> for Point2d<T>
+where
+    T: Coordinate,
+{
+    type Output = Point2d<T>;
+
+    fn mul(self, scalar: T) -> Point2d<T> {
+        Point2d::new(self.x * scalar, self.y * scalar)
+    }
 }
 
 impl<T> Location for Point2d<T>
 where
-    T: Add<Output = T>
-        + Sub<Output = T>
-        + Mul<Output = T>
-        + Div<Output = T>
-        + Ord
-        + Into<f64>
-        + Copy,
+    T: Coordinate,
 {
     type ValueOutput = T;
 
     fn manhattan_distance_to(&self, other: &Point2d<T>) -> T {
-        let relative_x = other.x - self.x;
-        let relative_y = other.y - self.y;
+        let relative_x = self.x.distance(other.x);
+        let relative_y = self.y.distance(other.y);
 
         relative_x + relative_y
     }
 
     fn distance_to(&self, other: &Point2d<T>) -> f64 {
-        let relative_x = other.x - self.x;
-        let relative_y = other.y - self.y;
+        let relative_x = self.x.distance(other.x).to_f64();
+        let relative_y = self.y.distance(other.y).to_f64();
 
-        let temp = (relative_x * relative_x + relative_y * relative_y).into();
-
-        temp.sqrt()
+        relative_x.hypot(relative_y)
     }
 
     fn add(&self, other: &Point2d<T>) -> Point2d<T> {
-        let new_x = self.x + other.x;
-        let new_y = self.y + other.y;
-
-        Point2d::new(new_x, new_y)
+        *self + *other
     }
 }
 
@@ -120,6 +162,80 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_manhattan_distance_to_negative_direction() {
+        let point = Point2d::new(-3, 5);
+
+        let expected = 8;
+
+        let result = ORIGIN_POINT.manhattan_distance_to(&point);
+
+        assert_eq!(result, expected);
+        assert_eq!(point.manhattan_distance_to(&ORIGIN_POINT), expected);
+    }
+
+    #[test]
+    fn test_manhattan_distance_to_unsigned() {
+        let first: Point2d<u64> = Point2d::new(10, 2);
+        let second: Point2d<u64> = Point2d::new(4, 7);
+
+        let expected = 11;
+
+        let result = first.manhattan_distance_to(&second);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_chebyshev_distance_to() {
+        let point: Point2d<i64> = Point2d::new(-3, 5);
+
+        let expected = 5;
+
+        let result = Point2d::new(0, 0).chebyshev_distance_to(&point);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_distance_to_i64() {
+        let point: Point2d<i64> = Point2d::new(-3_000_000_000, 4_000_000_000);
+
+        let expected = 5_000_000_000.0;
+
+        let result = Point2d::new(0, 0).distance_to(&point);
+
+        assert!((result - expected).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_ops() {
+        let first = Point2d::new(3, 4);
+        let second = Point2d::new(5, -1);
+
+        assert_eq!(first + second, Point2d::new(8, 3));
+        assert_eq!(first - second, Point2d::new(-2, 5));
+        assert_eq!(-first, Point2d::new(-3, -4));
+        assert_eq!(first * 3, Point2d::new(9, 12));
+    }
+
+    #[test]
+    fn test_rotate() {
+        let point = Point2d::new(3, 1);
+
+        assert_eq!(point.rotate_left(), Point2d::new(-1, 3));
+        assert_eq!(point.rotate_right(), Point2d::new(1, -3));
+        assert_eq!(point.rotate_left().rotate_right(), point);
+        assert_eq!(
+            point
+                .rotate_left()
+                .rotate_left()
+                .rotate_left()
+                .rotate_left(),
+            point
+        );
+    }
+
     #[test]
     fn test_distance_to() {
         let point = Point2d::new(3, 4);
@@ -0,0 +1,259 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::coordinate::Coordinate;
+use super::location::Location;
+use super::point_n::PointN;
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Hash, Copy, Clone, Ord)]
+pub struct Point3d<T>
+where
+    T: Coordinate,
+{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> From<(T, T, T)> for Point3d<T>
+where
+    T: Coordinate,
+{
+    fn from((x, y, z): (T, T, T)) -> Point3d<T> {
+        Point3d { x, y, z }
+    }
+}
+
+impl<T> From<PointN<T, 3>> for Point3d<T>
+where
+    T: Coordinate,
+{
+    fn from(point: PointN<T, 3>) -> Point3d<T> {
+        let [x, y, z] = point.coordinates;
+
+        Point3d { x, y, z }
+    }
+}
+
+impl<T> From<Point3d<T>> for PointN<T, 3>
+where
+    T: Coordinate,
+{
+    fn from(point: Point3d<T>) -> PointN<T, 3> {
+        PointN::new([point.x, point.y, point.z])
+    }
+}
+
+impl<T> Point3d<T>
+where
+    T: Coordinate,
+{
+    pub fn new(x: T, y: T, z: T) -> Point3d<T> {
+        Point3d { x, y, z }
+    }
+
+    /// The largest distance along any axis.
+    pub fn chebyshev_distance_to(&self, other: &Point3d<T>) -> T {
+        PointN::from(*self).chebyshev_distance_to(&PointN::from(*other))
+    }
+
+    /// The points sharing a face with this one.
+    ///
+    /// Points whose coordinates would overflow are skipped.
+    pub fn neighbors_6(&self) -> Vec<Point3d<T>> {
+        PointN::from(*self)
+            .axis_neighbors()
+            .into_iter()
+            .map(Point3d::from)
+            .collect()
+    }
+
+    /// The points sharing a face, edge or corner with this one.
+    ///
+    /// Points whose coordinates would overflow are skipped.
+    pub fn neighbors_26(&self) -> Vec<Point3d<T>> {
+        PointN::from(*self)
+            .all_neighbors()
+            .into_iter()
+            .map(Point3d::from)
+            .collect()
+    }
+
+    /// The smallest and largest corners of the box containing every point, or `None` if there
+    /// aren't any.
+    pub fn bounding_box<'a, I>(points: I) -> Option<(Point3d<T>, Point3d<T>)>
+    where
+        I: IntoIterator<Item = &'a Point3d<T>>,
+        T: 'a,
+    {
+        let points: Vec<PointN<T, 3>> = points.into_iter().copied().map(PointN::from).collect();
+
+        PointN::bounding_box(&points).map(|(min, max)| (Point3d::from(min), Point3d::from(max)))
+    }
+}
+
+impl<T> Location for Point3d<T>
+where
+    T: Coordinate,
+{
+    type ValueOutput = T;
+
+    fn manhattan_distance_to(&self, other: &Point3d<T>) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    fn distance_to(&self, other: &Point3d<T>) -> f64 {
+        PointN::from(*self).distance_to(&PointN::from(*other))
+    }
+
+    fn add(&self, other: &Point3d<T>) -> Point3d<T> {
+        *self + *other
+    }
+}
+
+impl<T> Add for Point3d<T>
+where
+    T: Coordinate,
+{
+    type Output = Point3d<T>;
+
+    fn add(self, other: Point3d<T>) -> Point3d<T> {
+        Point3d::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T> Sub for Point3d<T>
+where
+    T: Coordinate,
+{
+    type Output = Point3d<T>;
+
+    fn sub(self, other: Point3d<T>) -> Point3d<T> {
+        Point3d::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T> Neg for Point3d<T>
+where
+    T: Coordinate + Neg<Output = T>,
+{
+    type Output = Point3d<T>;
+
+    fn neg(self) -> Point3d<T> {
+        Point3d::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T> Mul<T> for Point3d<T>
+where
+    T: Coordinate,
+{
+    type Output = Point3d<T>;
+
+    fn mul(self, scalar: T) -> Point3d<T> {
+        Point3d::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-10;
+
+    const ORIGIN_POINT: Point3d<i64> = Point3d { x: 0, y: 0, z: 0 };
+
+    #[test]
+    fn test_from() {
+        let expected = Point3d::from((1, 2, 3));
+
+        let result = Point3d { x: 1, y: 2, z: 3 };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_manhattan_distance_to() {
+        let point = Point3d::new(-1, 2, -3);
+
+        let expected = 6;
+
+        let result = ORIGIN_POINT.manhattan_distance_to(&point);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_distance_to() {
+        let point = Point3d::new(2, -3, 6);
+
+        let expected = 7.0;
+
+        let result = ORIGIN_POINT.distance_to(&point);
+
+        assert!((result - expected).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_chebyshev_distance_to() {
+        let point = Point3d::new(2, -7, 6);
+
+        let expected = 7;
+
+        let result = ORIGIN_POINT.chebyshev_distance_to(&point);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ops() {
+        let first = Point3d::new(1, 2, 3);
+        let second = Point3d::new(3, -2, 1);
+
+        assert_eq!(first + second, Point3d::new(4, 0, 4));
+        assert_eq!(first - second, Point3d::new(-2, 4, 2));
+        assert_eq!(-first, Point3d::new(-1, -2, -3));
+        assert_eq!(first * 3, Point3d::new(3, 6, 9));
+        assert_eq!(Location::add(&first, &second), first + second);
+    }
+
+    #[test]
+    fn test_neighbors_6() {
+        let expected = vec![
+            Point3d::new(-1, 0, 0),
+            Point3d::new(0, -1, 0),
+            Point3d::new(0, 0, -1),
+            Point3d::new(0, 0, 1),
+            Point3d::new(0, 1, 0),
+            Point3d::new(1, 0, 0),
+        ];
+
+        let result = ORIGIN_POINT.neighbors_6();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_neighbors_26() {
+        let result = ORIGIN_POINT.neighbors_26();
+
+        assert_eq!(result.len(), 26);
+        assert!(result.contains(&Point3d::new(1, -1, 1)));
+        assert!(!result.contains(&ORIGIN_POINT));
+        assert_eq!(Point3d::new(0_u32, 0, 0).neighbors_26().len(), 7);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [
+            Point3d::new(1, 0, 1),
+            Point3d::new(1, 2, 1),
+            Point3d::new(0, 0, 2),
+        ];
+
+        let expected = (Point3d::new(0, 0, 1), Point3d::new(1, 2, 2));
+
+        let result = Point3d::bounding_box(&points).unwrap();
+
+        assert_eq!(result, expected);
+    }
+}
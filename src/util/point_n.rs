@@ -0,0 +1,322 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::coordinate::Coordinate;
+use super::location::Location;
+
+const STEPS: [Ordering; 3] = [Ordering::Less, Ordering::Equal, Ordering::Greater];
+
+/// A point with `N` coordinates.
+#[derive(Debug, Eq, PartialEq, PartialOrd, Hash, Copy, Clone, Ord)]
+pub struct PointN<T, const N: usize>
+where
+    T: Coordinate,
+{
+    pub coordinates: [T; N],
+}
+
+impl<T, const N: usize> From<[T; N]> for PointN<T, N>
+where
+    T: Coordinate,
+{
+    fn from(coordinates: [T; N]) -> PointN<T, N> {
+        PointN { coordinates }
+    }
+}
+
+impl<T, const N: usize> PointN<T, N>
+where
+    T: Coordinate,
+{
+    pub fn new(coordinates: [T; N]) -> PointN<T, N> {
+        PointN { coordinates }
+    }
+
+    /// The largest distance along any axis.
+    pub fn chebyshev_distance_to(&self, other: &PointN<T, N>) -> T {
+        self.coordinates
+            .iter()
+            .zip(other.coordinates)
+            .map(|(&value, other_value)| value.distance(other_value))
+            .max()
+            .unwrap_or(T::ZERO)
+    }
+
+    /// The `2 * N` points one step away along a single axis.
+    ///
+    /// Points whose coordinates would overflow are skipped.
+    pub fn axis_neighbors(&self) -> Vec<PointN<T, N>> {
+        self.neighbors()
+            .filter(|(steps, _)| {
+                steps
+                    .iter()
+                    .filter(|&&step| step != Ordering::Equal)
+                    .count()
+                    == 1
+            })
+            .map(|(_, neighbor)| neighbor)
+            .collect()
+    }
+
+    /// The `3^N - 1` points one step away along any combination of axes, including diagonals.
+    ///
+    /// Points whose coordinates would overflow are skipped.
+    pub fn all_neighbors(&self) -> Vec<PointN<T, N>> {
+        self.neighbors().map(|(_, neighbor)| neighbor).collect()
+    }
+
+    /// Every neighbor in lexicographic order of the steps taken along each axis.
+    fn neighbors(&self) -> impl Iterator<Item = ([Ordering; N], PointN<T, N>)> + '_ {
+        let combinations = (0..N).fold(1, |total, _| total * STEPS.len());
+
+        (0..combinations)
+            .map(|combination| {
+                let mut steps = [Ordering::Equal; N];
+                let mut remaining = combination;
+
+                for step in steps.iter_mut().rev() {
+                    *step = STEPS[remaining % STEPS.len()];
+                    remaining /= STEPS.len();
+                }
+
+                steps
+            })
+            .filter(|steps| steps.iter().any(|&step| step != Ordering::Equal))
+            .filter_map(|steps| {
+                let mut coordinates = self.coordinates;
+
+                for (value, step) in coordinates.iter_mut().zip(steps) {
+                    *value = value.step(step)?;
+                }
+
+                Some((steps, PointN { coordinates }))
+            })
+    }
+
+    /// The smallest and largest corners of the box containing every point, or `None` if there
+    /// aren't any.
+    pub fn bounding_box<'a, I>(points: I) -> Option<(PointN<T, N>, PointN<T, N>)>
+    where
+        I: IntoIterator<Item = &'a PointN<T, N>>,
+        T: 'a,
+    {
+        let mut points = points.into_iter();
+
+        let first = *points.next()?;
+
+        Some(points.fold((first, first), |(mut min, mut max), point| {
+            for index in 0..N {
+                min.coordinates[index] = min.coordinates[index].min(point.coordinates[index]);
+                max.coordinates[index] = max.coordinates[index].max(point.coordinates[index]);
+            }
+
+            (min, max)
+        }))
+    }
+
+    fn zip_with<F>(&self, other: &PointN<T, N>, combine: F) -> PointN<T, N>
+    where
+        F: Fn(T, T) -> T,
+    {
+        let mut coordinates = self.coordinates;
+
+        for (value, other_value) in coordinates.iter_mut().zip(other.coordinates) {
+            *value = combine(*value, other_value);
+        }
+
+        PointN { coordinates }
+    }
+}
+
+impl<T, const N: usize> Location for PointN<T, N>
+where
+    T: Coordinate,
+{
+    type ValueOutput = T;
+
+    fn manhattan_distance_to(&self, other: &PointN<T, N>) -> T {
+        self.coordinates
+            .iter()
+            .zip(other.coordinates)
+            .fold(T::ZERO, |total, (&value, other_value)| {
+                total + value.distance(other_value)
+            })
+    }
+
+    fn distance_to(&self, other: &PointN<T, N>) -> f64 {
+        self.coordinates
+            .iter()
+            .zip(other.coordinates)
+            .map(|(&value, other_value)| value.distance(other_value).to_f64().powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    fn add(&self, other: &PointN<T, N>) -> PointN<T, N> {
+        *self + *other
+    }
+}
+
+impl<T, const N: usize> Add for PointN<T, N>
+where
+    T: Coordinate,
+{
+    type Output = PointN<T, N>;
+
+    fn add(self, other: PointN<T, N>) -> PointN<T, N> {
+        self.zip_with(&other, |value, other_value| value + other_value)
+    }
+}
+
+impl<T, const N: usize> Sub for PointN<T, N>
+where
+    T: Coordinate,
+{
+    type Output = PointN<T, N>;
+
+    fn sub(self, other: PointN<T, N>) -> PointN<T, N> {
+        self.zip_with(&other, |value, other_value| value - other_value)
+    }
+}
+
+impl<T, const N: usize> Neg for PointN<T, N>
+where
+    T: Coordinate + Neg<Output = T>,
+{
+    type Output = PointN<T, N>;
+
+    fn neg(self) -> PointN<T, N> {
+        PointN {
+            coordinates: self.coordinates.map(|value| -value),
+        }
+    }
+}
+
+impl<T, const N: usize> Mul<T> for PointN<T, N>
+where
+    T: Coordinate,
+{
+    type Output = PointN<T, N>;
+
+    fn mul(self, scalar: T) -> PointN<T, N> {
+        PointN {
+            coordinates: self.coordinates.map(|value| value * scalar),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-10;
+
+    #[test]
+    fn test_manhattan_distance_to() {
+        let first = PointN::new([1, -2, 3, 0]);
+        let second = PointN::new([-1, 2, 3, 5]);
+
+        let expected = 11;
+
+        let result = first.manhattan_distance_to(&second);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_distance_to() {
+        let first: PointN<u64, 4> = PointN::new([1, 1, 1, 1]);
+        let second = PointN::new([2, 2, 2, 2]);
+
+        let expected = 2.0;
+
+        let result = first.distance_to(&second);
+
+        assert!((result - expected).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_chebyshev_distance_to() {
+        let first = PointN::new([1, -2, 3, 0]);
+        let second = PointN::new([-1, 2, 3, 3]);
+
+        let expected = 4;
+
+        let result = first.chebyshev_distance_to(&second);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ops() {
+        let first = PointN::new([1, 2, 3, 4]);
+        let second = PointN::new([4, 3, 2, 1]);
+
+        assert_eq!(first + second, PointN::new([5, 5, 5, 5]));
+        assert_eq!(first - second, PointN::new([-3, -1, 1, 3]));
+        assert_eq!(-first, PointN::new([-1, -2, -3, -4]));
+        assert_eq!(first * 2, PointN::new([2, 4, 6, 8]));
+        assert_eq!(Location::add(&first, &second), first + second);
+    }
+
+    #[test]
+    fn test_axis_neighbors() {
+        let point = PointN::new([0, 0]);
+
+        let expected = vec![
+            PointN::new([-1, 0]),
+            PointN::new([0, -1]),
+            PointN::new([0, 1]),
+            PointN::new([1, 0]),
+        ];
+
+        let result = point.axis_neighbors();
+
+        assert_eq!(result, expected);
+        assert_eq!(PointN::new([0, 0, 0, 0]).axis_neighbors().len(), 8);
+    }
+
+    #[test]
+    fn test_all_neighbors() {
+        let point = PointN::new([5, 5, 5, 5]);
+
+        let result = point.all_neighbors();
+
+        assert_eq!(result.len(), 80);
+        assert!(!result.contains(&point));
+        assert!(result
+            .iter()
+            .all(|neighbor| neighbor.chebyshev_distance_to(&point) == 1));
+    }
+
+    #[test]
+    fn test_neighbors_skip_overflow() {
+        let point: PointN<u8, 2> = PointN::new([0, 255]);
+
+        let expected = vec![
+            PointN::new([0, 254]),
+            PointN::new([1, 254]),
+            PointN::new([1, 255]),
+        ];
+
+        let result = point.all_neighbors();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [
+            PointN::new([1, 5, -2]),
+            PointN::new([-3, 2, 0]),
+            PointN::new([4, 3, -1]),
+        ];
+
+        let expected = (PointN::new([-3, 2, -2]), PointN::new([4, 5, 0]));
+
+        let result = PointN::bounding_box(&points).unwrap();
+
+        assert_eq!(result, expected);
+        assert_eq!(PointN::<i32, 3>::bounding_box(&[]), None);
+    }
+}
@@ -0,0 +1,324 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use super::coordinate::Coordinate;
+
+/// The cheapest way found from the start to a goal, including both ends.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Breadth-first search, where every step costs one.
+///
+/// Returns `None` if no goal is reachable.
+pub fn bfs<S, I, F, G>(start: S, mut successors: F, mut is_goal: G) -> Option<Path<S, usize>>
+where
+    S: Eq + Hash + Clone,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+
+    while let Some((index, distance)) = queue.pop_front() {
+        if is_goal(visited.state(index)) {
+            return Some(Path {
+                cost: distance,
+                states: visited.path_to(index),
+            });
+        }
+
+        for next in successors(visited.state(index)) {
+            if let Ok(next_index) = visited.insert(next, index) {
+                queue.push_back((next_index, distance + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// The number of steps to every state reachable from the start, including the start itself.
+pub fn bfs_distances<S, I, F>(start: S, mut successors: F) -> HashMap<S, usize>
+where
+    S: Eq + Hash + Clone,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> I,
+{
+    let mut result = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, distance)) = queue.pop_front() {
+        for next in successors(&state) {
+            if !result.contains_key(&next) {
+                result.insert(next.clone(), distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm, where `successors` gives each next state with the cost of stepping to it.
+///
+/// Returns `None` if no goal is reachable.
+pub fn dijkstra<S, C, I, F, G>(start: S, successors: F, is_goal: G) -> Option<Path<S, C>>
+where
+    S: Eq + Hash + Clone,
+    C: Coordinate,
+    I: IntoIterator<Item = (S, C)>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    astar(start, successors, |_| C::ZERO, is_goal)
+}
+
+/// A* search, where `heuristic` estimates the remaining cost to a goal.
+///
+/// The path is only guaranteed to be the cheapest if the heuristic never overestimates.
+/// Returns `None` if no goal is reachable.
+pub fn astar<S, C, I, F, H, G>(
+    start: S,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Path<S, C>>
+where
+    S: Eq + Hash + Clone,
+    C: Coordinate,
+    I: IntoIterator<Item = (S, C)>,
+    F: FnMut(&S) -> I,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::ZERO, 0))]);
+    let mut visited = Visited::new(start);
+    let mut costs = vec![C::ZERO];
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+
+        if is_goal(visited.state(index)) {
+            return Some(Path {
+                cost,
+                states: visited.path_to(index),
+            });
+        }
+
+        for (next, step_cost) in successors(visited.state(index)) {
+            let next_cost = cost + step_cost;
+            let estimate = next_cost + heuristic(&next);
+
+            let next_index = match visited.insert(next, index) {
+                Ok(next_index) => {
+                    costs.push(next_cost);
+
+                    next_index
+                }
+                Err(next_index) if costs[next_index] <= next_cost => continue,
+                Err(next_index) => {
+                    visited.set_parent(next_index, index);
+                    costs[next_index] = next_cost;
+
+                    next_index
+                }
+            };
+
+            queue.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+/// Every state seen so far and the state it was first reached from, so paths can be rebuilt.
+struct Visited<S> {
+    states: Vec<S>,
+    parents: Vec<Option<usize>>,
+    indices: HashMap<S, usize>,
+}
+
+impl<S> Visited<S>
+where
+    S: Eq + Hash + Clone,
+{
+    fn new(start: S) -> Self {
+        Visited {
+            states: vec![start.clone()],
+            parents: vec![None],
+            indices: HashMap::from([(start, 0)]),
+        }
+    }
+
+    fn state(&self, index: usize) -> &S {
+        &self.states[index]
+    }
+
+    /// Adds a state reached from `parent`, returning its index, or the index it already has as an
+    /// error if it was seen before.
+    fn insert(&mut self, state: S, parent: usize) -> Result<usize, usize> {
+        if let Some(&index) = self.indices.get(&state) {
+            return Err(index);
+        }
+
+        let index = self.states.len();
+
+        self.states.push(state.clone());
+        self.parents.push(Some(parent));
+        self.indices.insert(state, index);
+
+        Ok(index)
+    }
+
+    fn set_parent(&mut self, index: usize, parent: usize) {
+        self.parents[index] = Some(parent);
+    }
+
+    fn path_to(&self, index: usize) -> Vec<S> {
+        let mut result = vec![self.states[index].clone()];
+        let mut current = index;
+
+        while let Some(parent) = self.parents[current] {
+            result.push(self.states[parent].clone());
+            current = parent;
+        }
+
+        result.reverse();
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::util::direction::Direction;
+    use crate::util::grid::Grid;
+    use crate::util::location::Location;
+    use crate::util::point_2d::Point2d;
+
+    fn to_grid(input: &[&str]) -> Grid<char> {
+        let lines: Vec<String> = input.iter().map(|line| (*line).to_string()).collect();
+
+        Grid::from_lines(&lines).unwrap()
+    }
+
+    fn open_neighbors(grid: &Grid<char>, point: Point2d<i32>) -> Vec<Point2d<i32>> {
+        grid.neighbors_4(point)
+            .filter(|neighbor| grid.get(*neighbor) != Some(&'#'))
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = to_grid(&["..#", "#..", "..."]);
+        let wall = Point2d::new(2, 0);
+
+        let expected = Path {
+            cost: 4,
+            states: vec![
+                Point2d::new(0, 0),
+                Point2d::new(1, 0),
+                Point2d::new(1, 1),
+                Point2d::new(2, 1),
+                Point2d::new(2, 2),
+            ],
+        };
+
+        let result = bfs(
+            Point2d::new(0, 0),
+            |point| open_neighbors(&grid, *point),
+            |point| *point == Point2d::new(2, 2),
+        );
+
+        assert_eq!(result, Some(expected));
+        assert_eq!(
+            bfs(
+                Point2d::new(0, 0),
+                |point| open_neighbors(&grid, *point),
+                |point| *point == wall
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let expected = HashMap::from([(0, 0), (1, 1), (2, 2), (3, 1)]);
+
+        let result = bfs_distances(0, |&state| [(state + 1) % 4, (state + 3) % 4]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let edges: HashMap<char, Vec<(char, u32)>> = HashMap::from([
+            ('a', vec![('b', 7), ('c', 9), ('f', 14)]),
+            ('b', vec![('c', 10), ('d', 15)]),
+            ('c', vec![('d', 11), ('f', 2)]),
+            ('d', vec![('e', 6)]),
+            ('f', vec![('e', 9)]),
+        ]);
+
+        let expected = Path {
+            cost: 20,
+            states: vec!['a', 'c', 'f', 'e'],
+        };
+
+        let result = dijkstra(
+            'a',
+            |state| edges.get(state).cloned().unwrap_or_default(),
+            |&state| state == 'e',
+        );
+
+        assert_eq!(result, Some(expected));
+        assert_eq!(
+            dijkstra(
+                'e',
+                |state| edges.get(state).cloned().unwrap_or_default(),
+                |&state| state == 'a'
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = to_grid(&[".....", ".###.", "...#.", ".#...", "....."]);
+        let goal = Point2d::new(4, 4);
+
+        let successors = |point: &Point2d<i32>| {
+            Direction::ALL
+                .iter()
+                .filter_map(|direction| direction.step(*point))
+                .filter(|neighbor| grid.get(*neighbor) == Some(&'.'))
+                .map(|neighbor| (neighbor, 1))
+                .collect::<Vec<(Point2d<i32>, i32)>>()
+        };
+
+        let result = astar(
+            Point2d::new(0, 0),
+            successors,
+            |point| point.manhattan_distance_to(&goal),
+            |point| *point == goal,
+        )
+        .unwrap();
+
+        let expected = dijkstra(Point2d::new(0, 0), successors, |point| *point == goal).unwrap();
+
+        assert_eq!(result.cost, 8);
+        assert_eq!(result.cost, expected.cost);
+        assert_eq!(result.states.len(), 9);
+        assert_eq!(result.states.first(), Some(&Point2d::new(0, 0)));
+        assert_eq!(result.states.last(), Some(&goal));
+    }
+}
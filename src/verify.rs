@@ -0,0 +1,269 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::runner::{self, PartResult};
+use crate::solution::{Part, Registry};
+use crate::table;
+
+/// Expected answers keyed by day and part.
+pub type Answers = BTreeMap<(u32, Part), String>;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Status {
+    Match,
+    Mismatch,
+    NoExpectedAnswer,
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Match => write!(f, "ok"),
+            Status::Mismatch => write!(f, "MISMATCH"),
+            Status::NoExpectedAnswer => write!(f, "no answer"),
+            Status::Error => write!(f, "ERROR"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Check {
+    pub day: u32,
+    pub part: Part,
+    pub expected: Option<String>,
+    pub actual: Result<String, String>,
+}
+
+impl Check {
+    #[must_use]
+    pub fn status(&self) -> Status {
+        match (&self.expected, &self.actual) {
+            (_, Err(_)) => Status::Error,
+            (None, Ok(_)) => Status::NoExpectedAnswer,
+            (Some(expected), Ok(actual)) if expected == actual => Status::Match,
+            (Some(_), Ok(_)) => Status::Mismatch,
+        }
+    }
+
+    #[must_use]
+    pub fn is_regression(&self) -> bool {
+        matches!(self.status(), Status::Mismatch | Status::Error)
+    }
+}
+
+/// Parses an answers file where each line is `<day> <part> <expected>`.
+///
+/// Blank lines and lines starting with `#` are skipped.
+///
+/// # Errors
+///
+/// If a line doesn't have exactly three values, or the day/part can't be parsed.
+pub fn parse_answers(lines: &[String]) -> Result<Answers, String> {
+    let mut answers = Answers::new();
+
+    for (line_index, line) in lines.iter().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let values: Vec<&str> = line.split_whitespace().collect();
+
+        let [day_str, part_str, expected] = values[..] else {
+            return Err(format!(
+                "Line {}: '{line}' should be '<day> <part> <expected>'!",
+                line_index + 1
+            ));
+        };
+
+        let day = day_str
+            .parse()
+            .map_err(|_| format!("Line {}: '{day_str}' is not a day!", line_index + 1))?;
+        let part = part_str
+            .parse()
+            .map_err(|error| format!("Line {}: {error}", line_index + 1))?;
+
+        answers.insert((day, part), expected.to_string());
+    }
+
+    Ok(answers)
+}
+
+/// Runs every registered day and checks its answers against `answers`.
+///
+/// Expected answers for days that aren't registered are reported as errors, as are days that
+/// panic, without stopping the other days from being checked.
+pub fn verify<F>(registry: &Registry, answers: &Answers, read_input: F) -> Vec<Check>
+where
+    F: Fn(u32) -> Result<Vec<String>, String>,
+{
+    let mut checks = Vec::new();
+
+    for (day, solution) in registry.iter() {
+        let results = match read_input(day) {
+            Ok(input) => runner::run_parts(day, solution, &input, &Part::ALL),
+            Err(error) => PartResult::failed(day, &Part::ALL, &error),
+        };
+
+        checks.extend(results.into_iter().map(|result| Check {
+            day,
+            part: result.part,
+            expected: answers.get(&(day, result.part)).cloned(),
+            actual: result.answer.map(|answer| answer.value),
+        }));
+    }
+
+    for (&(day, part), expected) in answers {
+        if registry.get(day).is_none() {
+            checks.push(Check {
+                day,
+                part,
+                expected: Some(expected.clone()),
+                actual: Err(format!("Day {day} isn't registered!")),
+            });
+        }
+    }
+
+    checks
+}
+
+/// Formats the checks as an aligned table.
+#[must_use]
+pub fn report(checks: &[Check]) -> String {
+    let rows: Vec<[String; 5]> = checks
+        .iter()
+        .map(|check| {
+            [
+                check.day.to_string(),
+                check.part.to_string(),
+                check.expected.clone().unwrap_or_else(|| "-".to_string()),
+                check.actual.clone().unwrap_or_else(|error| error),
+                check.status().to_string(),
+            ]
+        })
+        .collect();
+
+    table::format_table(["Day", "Part", "Expected", "Actual", "Status"], &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::solution::test_support::Summer;
+
+    fn read_input(day: u32) -> Result<Vec<String>, String> {
+        match day {
+            1 => Ok(vec!["1".to_string(), "2".to_string()]),
+            3 => Ok(vec!["three".to_string()]),
+            _ => Err(format!("No input for day {day}!")),
+        }
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let lines = [
+            "# day part expected".to_string(),
+            "1 1 142".to_string(),
+            String::new(),
+            "1 2   281".to_string(),
+        ];
+
+        let expected = Answers::from([
+            ((1, Part::One), "142".to_string()),
+            ((1, Part::Two), "281".to_string()),
+        ]);
+
+        let result = parse_answers(&lines).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_answers_err() {
+        assert!(parse_answers(&["1 1".to_string()]).is_err());
+        assert!(parse_answers(&["one 1 142".to_string()]).is_err());
+        assert!(parse_answers(&["1 3 142".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut registry = Registry::new();
+
+        registry.register(1, Summer);
+        registry.register(2, Summer);
+        registry.register(3, Summer);
+
+        let answers = Answers::from([
+            ((1, Part::One), "3".to_string()),
+            ((1, Part::Two), "3".to_string()),
+            ((5, Part::One), "10".to_string()),
+        ]);
+
+        let expected_statuses = vec![
+            (1, Part::One, Status::Match),
+            (1, Part::Two, Status::Mismatch),
+            (2, Part::One, Status::Error),
+            (2, Part::Two, Status::Error),
+            (3, Part::One, Status::Error),
+            (3, Part::Two, Status::Error),
+            (5, Part::One, Status::Error),
+        ];
+
+        let result = verify(&registry, &answers, read_input);
+
+        let result_statuses: Vec<(u32, Part, Status)> = result
+            .iter()
+            .map(|check| (check.day, check.part, check.status()))
+            .collect();
+
+        assert_eq!(result_statuses, expected_statuses);
+        assert_eq!(result[1].actual, Ok("2".to_string()));
+        assert!(result[4]
+            .actual
+            .as_ref()
+            .is_err_and(|error| error.starts_with("Panicked: ")));
+    }
+
+    #[test]
+    fn test_check_status_no_expected_answer() {
+        let check = Check {
+            day: 1,
+            part: Part::One,
+            expected: None,
+            actual: Ok("3".to_string()),
+        };
+
+        assert_eq!(check.status(), Status::NoExpectedAnswer);
+        assert!(!check.is_regression());
+    }
+
+    #[test]
+    fn test_report() {
+        let checks = [
+            Check {
+                day: 1,
+                part: Part::One,
+                expected: Some("142".to_string()),
+                actual: Ok("142".to_string()),
+            },
+            Check {
+                day: 10,
+                part: Part::Two,
+                expected: None,
+                actual: Ok("8".to_string()),
+            },
+        ];
+
+        let expected = "\
+Day | Part | Expected | Actual | Status
+1   | 1    | 142      | 142    | ok
+10  | 2    | -        | 8      | no answer";
+
+        let result = report(&checks);
+
+        assert_eq!(result, expected);
+    }
+}
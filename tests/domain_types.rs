@@ -0,0 +1,83 @@
+use advent_of_code_2023::day_1::CalibrationValue;
+use advent_of_code_2023::day_10::PipeNetwork;
+use advent_of_code_2023::day_2::{CubeCount, Game};
+use advent_of_code_2023::day_5::Almanac;
+use advent_of_code_2023::day_7::Hand;
+use advent_of_code_2023::day_9::Oasis;
+use advent_of_code_2023::util::file_reader::to_string_vector;
+use advent_of_code_2023::util::math;
+use advent_of_code_2023::util::point_2d::Point2d;
+
+#[test]
+fn test_calibration_value() {
+    let expected = CalibrationValue(38);
+
+    let result = "pqr3stu8vwx".parse::<CalibrationValue>().unwrap();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_game() {
+    let game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+        .parse::<Game>()
+        .unwrap();
+
+    assert!(game.can_contain(CubeCount::new(14, 13, 12)));
+    assert!(!game.can_contain(CubeCount::new(5, 13, 12)));
+}
+
+#[test]
+fn test_almanac() {
+    let input = to_string_vector("test_inputs/day_5.txt").unwrap();
+
+    let almanac = Almanac::new(&input);
+
+    assert_eq!(almanac.seed_location(79), 82);
+    assert_eq!(
+        almanac.lowest_location_from_seed_ranges(&[(79, 14), (55, 13)]),
+        46
+    );
+}
+
+#[test]
+fn test_hand_ordering() {
+    let weaker = "KTJJT 220".parse::<Hand>().unwrap();
+    let stronger = "KK677 28".parse::<Hand>().unwrap();
+
+    assert!(weaker < stronger);
+}
+
+#[test]
+fn test_oasis() {
+    let input = to_string_vector("test_inputs/day_9.txt").unwrap();
+
+    let oasis = Oasis::new(&input);
+
+    assert_eq!(oasis.sum_of_next_values(), 114);
+    assert_eq!(oasis.sum_of_previous_values(), 2);
+}
+
+#[test]
+fn test_pipe_network() {
+    let input = to_string_vector("test_inputs/day_10_part_1.txt").unwrap();
+
+    let network = PipeNetwork::new(&input);
+
+    assert_eq!(network.length_of_network_loop() / 2, 8);
+}
+
+#[test]
+fn test_util_math() {
+    assert_eq!(math::gcd(12_u64, 18), 6);
+    assert_eq!(math::lcm(4_u64, 6), 12);
+}
+
+#[test]
+fn test_util_point_2d() {
+    let expected = Point2d::new(3, 5);
+
+    let result = Point2d::new(1, 2).add_t((2, 3));
+
+    assert_eq!(result, expected);
+}
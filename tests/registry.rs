@@ -0,0 +1,60 @@
+use advent_of_code_2023::registry;
+use advent_of_code_2023::runner;
+use advent_of_code_2023::solution::Part;
+use advent_of_code_2023::util::file_reader::to_string_vector;
+
+fn solve(day: u32, part: Part, file_name: &str) -> String {
+    let registry = registry();
+
+    let solution = registry.get(day).expect("Day should be registered!");
+    let input = to_string_vector(file_name).expect("Test input should be readable!");
+
+    let results = runner::run_parts(day, solution, &input, &[part]);
+
+    results[0]
+        .answer
+        .as_ref()
+        .map(|answer| answer.value.clone())
+        .expect("Day should solve its test input!")
+}
+
+#[test]
+fn test_registry_days() {
+    let expected: Vec<u32> = (1..=10).collect();
+
+    let result: Vec<u32> = registry().days().collect();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_test_inputs() {
+    let cases = [
+        (1, Part::One, "test_inputs/day_1_part_1.txt", "142"),
+        (1, Part::Two, "test_inputs/day_1_part_2.txt", "281"),
+        (2, Part::One, "test_inputs/day_2.txt", "8"),
+        (2, Part::Two, "test_inputs/day_2.txt", "2286"),
+        (3, Part::One, "test_inputs/day_3.txt", "4361"),
+        (3, Part::Two, "test_inputs/day_3.txt", "467835"),
+        (4, Part::One, "test_inputs/day_4.txt", "13"),
+        (4, Part::Two, "test_inputs/day_4.txt", "30"),
+        (5, Part::One, "test_inputs/day_5.txt", "35"),
+        (5, Part::Two, "test_inputs/day_5.txt", "46"),
+        (6, Part::One, "test_inputs/day_6.txt", "288"),
+        (6, Part::Two, "test_inputs/day_6.txt", "71503"),
+        (7, Part::One, "test_inputs/day_7.txt", "6440"),
+        (7, Part::Two, "test_inputs/day_7.txt", "5905"),
+        (8, Part::One, "test_inputs/day_8.txt", "6"),
+        (8, Part::Two, "test_inputs/day_8.txt", "6"),
+        (9, Part::One, "test_inputs/day_9.txt", "114"),
+        (9, Part::Two, "test_inputs/day_9.txt", "2"),
+        (10, Part::One, "test_inputs/day_10_part_1.txt", "8"),
+        (10, Part::Two, "test_inputs/day_10_part_2.txt", "8"),
+    ];
+
+    for (day, part, file_name, expected) in cases {
+        let result = solve(day, part, file_name);
+
+        assert_eq!(result, expected, "Day {day} Part {part}");
+    }
+}